@@ -0,0 +1,252 @@
+use std::time::Duration;
+
+use aoc::error::Error;
+use aoc::json;
+use aoc::runner::{self, Status};
+use aoc::solution::Part;
+
+use crate::Format;
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+struct Stats {
+    min: Duration,
+    median: Duration,
+    max: Duration,
+}
+
+impl Stats {
+    fn new(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+enum Phase {
+    Parse,
+    Part(Part),
+    Solve,
+    Total,
+}
+
+impl Phase {
+    fn name(self) -> String {
+        match self {
+            Self::Parse => "parse".into(),
+            Self::Part(part) => format!("part{}", part),
+            Self::Solve => "solve".into(),
+            Self::Total => "total".into(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Bench {
+    year: usize,
+    day: usize,
+    runs: usize,
+    exit_code: u8,
+    phases: Result<Vec<(Phase, Stats)>, String>,
+}
+
+fn bench_day(
+    year: usize,
+    day: usize,
+    runs: usize,
+    input: Option<&str>,
+    parts: &[Part],
+) -> Result<Bench, Error> {
+    let mut samples = vec![];
+    for _ in 0..runs {
+        let report = runner::run_day(year, day, input, parts)?;
+        let Status::Solved(outcome) = report.status else {
+            return Ok(Bench {
+                year,
+                day,
+                runs,
+                exit_code: if report.status.skipped() {
+                    0
+                } else {
+                    report.status.exit_code()
+                },
+                phases: Err(report.status()),
+            });
+        };
+        let mut sample = vec![(Phase::Parse, outcome.parse)];
+        sample.extend(
+            outcome
+                .answers
+                .iter()
+                .map(|(part, _, time)| (Phase::Part(*part), *time)),
+        );
+        sample.push((Phase::Solve, outcome.solve()));
+        sample.push((Phase::Total, outcome.parse + outcome.solve()));
+        samples.push(sample);
+    }
+
+    let phases = samples.first().map_or_else(Vec::new, |sample| {
+        sample
+            .iter()
+            .enumerate()
+            .map(|(idx, (phase, _))| {
+                let mut times = samples
+                    .iter()
+                    .map(|sample| sample[idx].1)
+                    .collect::<Vec<_>>();
+                (*phase, Stats::new(&mut times))
+            })
+            .collect()
+    });
+    Ok(Bench {
+        year,
+        day,
+        runs,
+        exit_code: 0,
+        phases: Ok(phases),
+    })
+}
+
+fn print_text(benches: &[Bench]) {
+    println!(
+        "{:>4}  {:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+        "Year", "Day", "Phase", "Min", "Median", "Max"
+    );
+    for bench in benches {
+        match &bench.phases {
+            Ok(phases) => {
+                for (phase, stats) in phases {
+                    println!(
+                        "{:>4}  {:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+                        bench.year,
+                        bench.day,
+                        phase.name(),
+                        format!("{:.3?}", stats.min),
+                        format!("{:.3?}", stats.median),
+                        format!("{:.3?}", stats.max),
+                    );
+                }
+            }
+            Err(status) => println!("{:>4}  {:>3}  {}", bench.year, bench.day, status),
+        }
+    }
+}
+
+fn print_csv(benches: &[Bench]) {
+    println!("year,day,phase,runs,min_ns,median_ns,max_ns,status");
+    for bench in benches {
+        match &bench.phases {
+            Ok(phases) => {
+                for (phase, stats) in phases {
+                    println!(
+                        "{},{},{},{},{},{},{},ok",
+                        bench.year,
+                        bench.day,
+                        phase.name(),
+                        bench.runs,
+                        stats.min.as_nanos(),
+                        stats.median.as_nanos(),
+                        stats.max.as_nanos(),
+                    );
+                }
+            }
+            Err(status) => println!(
+                "{},{},,{},,,,\"{}\"",
+                bench.year,
+                bench.day,
+                bench.runs,
+                status.replace('"', "\"\"")
+            ),
+        }
+    }
+}
+
+fn print_json(benches: &[Bench]) {
+    for bench in benches {
+        let mut fields = vec![
+            ("year", bench.year.to_string()),
+            ("day", bench.day.to_string()),
+            ("runs", bench.runs.to_string()),
+        ];
+        match &bench.phases {
+            Ok(phases) => {
+                let phases = phases
+                    .iter()
+                    .map(|(phase, stats)| {
+                        json::object(&[
+                            ("phase", json::string(&phase.name())),
+                            ("min_ns", stats.min.as_nanos().to_string()),
+                            ("median_ns", stats.median.as_nanos().to_string()),
+                            ("max_ns", stats.max.as_nanos().to_string()),
+                        ])
+                    })
+                    .collect::<Vec<_>>();
+                fields.push(("status", json::string("ok")));
+                fields.push(("phases", json::array(&phases)));
+            }
+            Err(status) => fields.push(("status", json::string(status))),
+        }
+        println!("{}", json::object(&fields));
+    }
+}
+
+pub fn run(
+    days: &[(usize, usize)],
+    runs: usize,
+    input: Option<&str>,
+    parts: &[Part],
+    format: Format,
+) -> Result<u8, Error> {
+    if runs == 0 {
+        return Err(Error::runtime("Benchmark needs at least one run"));
+    }
+    let benches = days
+        .iter()
+        .map(|(year, day)| bench_day(*year, *day, runs, input, parts))
+        .collect::<Result<Vec<_>, _>>()?;
+    match format {
+        Format::Text => print_text(&benches),
+        Format::Csv => print_csv(&benches),
+        Format::Json => print_json(&benches),
+    }
+    Ok(benches
+        .iter()
+        .map(|bench| bench.exit_code)
+        .max()
+        .unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            Stats::new(&mut [ms(3), ms(1), ms(2)]),
+            Stats {
+                min: ms(1),
+                median: ms(2),
+                max: ms(3)
+            }
+        );
+        assert_eq!(
+            Stats::new(&mut [ms(4), ms(1), ms(2), ms(8)]),
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(8)
+            }
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Error {
+    Parse {
+        line: usize,
+        col: usize,
+        text: String,
+        msg: String,
+    },
+    Vm {
+        ip: u64,
+        msg: String,
+    },
+    NoSolution(String),
+    Unimplemented,
+    // A solver or tool failed for a reason other than its input not parsing,
+    // like a program that stopped producing output.
+    Runtime(String),
+}
+
+impl Error {
+    pub fn parse(line: usize, col: usize, text: &str, msg: impl fmt::Display) -> Self {
+        Self::Parse {
+            line,
+            col,
+            text: text.into(),
+            msg: msg.to_string(),
+        }
+    }
+
+    pub fn vm(ip: u64, msg: impl fmt::Display) -> Self {
+        Self::Vm {
+            ip,
+            msg: msg.to_string(),
+        }
+    }
+
+    pub fn no_solution(msg: impl fmt::Display) -> Self {
+        Self::NoSolution(msg.to_string())
+    }
+
+    pub fn runtime(msg: impl fmt::Display) -> Self {
+        Self::Runtime(msg.to_string())
+    }
+
+    // Move a parse error down by `lines`, for input parsed a block at a time.
+    #[must_use]
+    pub fn offset(self, lines: usize) -> Self {
+        match self {
+            Self::Parse {
+                line,
+                col,
+                text,
+                msg,
+            } => Self::Parse {
+                line: line + lines,
+                col,
+                text,
+                msg,
+            },
+            err => err,
+        }
+    }
+
+    // Render the error along with the offending input, if any.
+    pub fn render(&self) -> String {
+        match self {
+            Self::Parse {
+                line, col, text, ..
+            } => {
+                let gutter = line.to_string().len();
+                format!(
+                    "{}\n{:>w$} | {}\n{:>w$} | {:>c$}",
+                    self,
+                    line,
+                    text,
+                    "",
+                    "^",
+                    w = gutter,
+                    c = *col
+                )
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse { line, col, msg, .. } => {
+                write!(f, "parse error at line {}, column {}: {}", line, col, msg)
+            }
+            Self::Vm { ip, msg } => write!(f, "vm fault at ip {}: {}", ip, msg),
+            Self::NoSolution(msg) => write!(f, "no solution: {}", msg),
+            Self::Unimplemented => write!(f, "not implemented"),
+            Self::Runtime(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+// Parse each line of `input`, attaching the line number to any error.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.parse()
+                .map_err(|err| Error::parse(idx + 1, 1, line, err))
+        })
+        .collect()
+}
+
+// The column at which `field`, a slice of `line`, starts.
+pub fn column(line: &str, field: &str) -> usize {
+    (field.as_ptr() as usize).saturating_sub(line.as_ptr() as usize) + 1
+}
+
+// Parse `field`, a slice of line `lineno`, attaching its position to any error.
+pub fn parse_field<T>(lineno: usize, line: &str, field: &str) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    field
+        .parse()
+        .map_err(|err| Error::parse(lineno, column(line, field), line, err))
+}
+
+// Split `input` at blank lines, pairing each block with the number of lines
+// before it.
+pub fn blocks(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut skip = 0;
+    input.split("\n\n").map(move |block| {
+        let start = skip;
+        skip += block.lines().count() + 1;
+        (start, block)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines::<u32>("1\n2\n3"), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            parse_lines::<u32>("1\nx\n3"),
+            Err(Error::parse(2, 1, "x", "invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn test_parse_field() {
+        let line = "x=12, y=ab";
+        assert_eq!(parse_field::<u32>(3, line, &line[2..4]), Ok(12));
+        assert_eq!(
+            parse_field::<u32>(3, line, &line[8..]),
+            Err(Error::parse(3, 9, line, "invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "a\nb\n\nc\n\nd\ne\n";
+        assert_eq!(
+            blocks(input).collect::<Vec<_>>(),
+            vec![(0, "a\nb"), (3, "c"), (5, "d\ne\n")]
+        );
+        assert_eq!(
+            parse_lines::<u32>("1\nx").map_err(|err| err.offset(3)),
+            Err(Error::parse(5, 1, "x", "invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            Error::parse(12, 3, "abcd", "Invalid character").render(),
+            "parse error at line 12, column 3: Invalid character\n12 | abcd\n   |   ^"
+        );
+        assert_eq!(
+            Error::vm(4, "Invalid opcode 42").render(),
+            "vm fault at ip 4: Invalid opcode 42"
+        );
+    }
+}
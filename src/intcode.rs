@@ -0,0 +1,464 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, Write};
+
+use aoc::error::Error;
+use aoc::y19::intcode::{self, Intcode, Line, Runtime};
+
+const HELP: &str = "\
+step [N]       run N instructions (default 1)
+continue       run until a breakpoint, watchpoint, input or halt
+break ADDR     stop before the instruction at ADDR
+break op NAME  stop before any NAME instruction (add, mul, in, ...)
+break out      stop after any output
+watch ADDR     stop when the value at ADDR changes
+delete N       remove breakpoint or watchpoint N
+info           list breakpoints and watchpoints
+regs           show the instruction pointer, relative base and next instruction
+mem ADDR [N]   dump N cells (default 8) starting at ADDR
+set ADDR VAL   write VAL to ADDR
+input VAL...   queue input values
+quit           exit the debugger
+An empty line repeats the last command.";
+
+// Cells per row of a memory dump.
+const MEM_WIDTH: u64 = 8;
+
+type Program = Runtime<VecDeque<i64>, io::Sink>;
+
+pub fn disasm(code: &str) -> Result<u8, Error> {
+    for line in code.parse::<Intcode>()?.disassemble() {
+        println!("{}", line);
+    }
+    Ok(0)
+}
+
+pub fn asm(src: &str) -> Result<u8, Error> {
+    let prog = Intcode::assemble(src)?;
+    let code = prog.code.iter().map(i64::to_string).collect::<Vec<_>>();
+    println!("{}", code.join(","));
+    Ok(0)
+}
+
+// Run a program on `input` and write what it recorded to `path`, even if it
+// failed, then print its output.
+fn record(
+    code: &str,
+    path: &str,
+    input: &[&str],
+    setup: fn(Program) -> Program,
+    export: fn(&Program, &mut dyn Write) -> io::Result<()>,
+) -> Result<u8, Error> {
+    let input = input
+        .iter()
+        .map(|val| num(val).map_err(Error::runtime))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut rt = setup(code.parse::<Intcode>()?.exec().read_vec(&input));
+    let res = rt.run();
+    let file = File::create(path)
+        .map_err(|err| Error::runtime(format!("Failed to create {}: {}", path, err)))?;
+    let mut w = io::BufWriter::new(file);
+    export(&rt, &mut w)
+        .and_then(|()| w.flush())
+        .map_err(|err| Error::runtime(format!("Failed to write {}: {}", path, err)))?;
+    for val in res? {
+        println!("{}", val);
+    }
+    Ok(0)
+}
+
+pub fn trace(code: &str, path: &str, input: &[&str]) -> Result<u8, Error> {
+    record(code, path, input, Program::traced, |rt, w| {
+        rt.trace()
+            .unwrap_or_default()
+            .iter()
+            .try_for_each(|step| writeln!(w, "{}", step))
+    })
+}
+
+pub fn profile(code: &str, path: &str, input: &[&str]) -> Result<u8, Error> {
+    record(code, path, input, Program::profiled, |rt, w| {
+        rt.profile()
+            .map_or(Ok(()), |profile| write!(w, "{}", profile))
+    })
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+enum Break {
+    Addr(u64),
+    Op(String),
+    Output,
+    // The watched address and the value last seen there.
+    Watch(u64, i64),
+}
+
+impl fmt::Display for Break {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Addr(addr) => write!(f, "break at {}", addr),
+            Self::Op(op) => write!(f, "break on {}", op),
+            Self::Output => write!(f, "break on output"),
+            Self::Watch(addr, val) => write!(f, "watch [{}] = {}", addr, val),
+        }
+    }
+}
+
+fn num<T: std::str::FromStr>(arg: &str) -> Result<T, String> {
+    arg.parse().map_err(|_| format!("Invalid number {}", arg))
+}
+
+struct Debugger {
+    rt: Program,
+    breaks: Vec<Break>,
+    halted: bool,
+    // Whether a breakpoint on the next instruction has already stopped us.
+    at_break: bool,
+}
+
+impl Debugger {
+    fn new(prog: &Intcode) -> Self {
+        Self {
+            rt: prog.exec().read_vec(&[]),
+            breaks: vec![],
+            halted: false,
+            at_break: false,
+        }
+    }
+
+    // The first address or opcode breakpoint on the next instruction.
+    fn break_at(&self) -> Option<String> {
+        let op = match self.rt.current() {
+            Line::Instr { op, .. } => op,
+            Line::Data { .. } => "",
+        };
+        self.breaks.iter().enumerate().find_map(|(idx, b)| match b {
+            Break::Addr(addr) if *addr == self.rt.ptr() => {
+                Some(format!("breakpoint {}: address {}", idx + 1, addr))
+            }
+            Break::Op(name) if name == op => Some(format!("breakpoint {}: {}", idx + 1, name)),
+            _ => None,
+        })
+    }
+
+    // Run one instruction, returning why execution should stop, if it should.
+    fn step(&mut self, out: &mut Vec<String>) -> Option<String> {
+        if self.halted {
+            return Some("program has halted".into());
+        }
+        if !self.at_break {
+            if let Some(stop) = self.break_at() {
+                self.at_break = true;
+                return Some(stop);
+            }
+        }
+        if self.rt.awaiting_input() {
+            return Some("waiting for input (use `input VAL...`)".into());
+        }
+        let mut stop = None;
+        match self.rt.next() {
+            None => {
+                self.halted = true;
+                return Some("program halted".into());
+            }
+            Some(Err(err)) => return Some(format!("error: {}", err)),
+            Some(Ok(val)) => {
+                self.at_break = false;
+                if let Some(val) = val {
+                    out.push(format!("output: {}", val));
+                    if let Some(idx) = self.breaks.iter().position(|b| *b == Break::Output) {
+                        stop = Some(format!("breakpoint {}: output {}", idx + 1, val));
+                    }
+                }
+            }
+        }
+
+        for (idx, b) in self.breaks.iter_mut().enumerate() {
+            if let Break::Watch(addr, old) = b {
+                let new = self.rt[*addr];
+                if new != *old {
+                    stop.get_or_insert(format!(
+                        "watchpoint {}: [{}] {} -> {}",
+                        idx + 1,
+                        addr,
+                        old,
+                        new
+                    ));
+                    *old = new;
+                }
+            }
+        }
+
+        if let Some(brk) = self.break_at() {
+            self.at_break = true;
+            stop.get_or_insert(brk);
+        }
+        stop
+    }
+
+    // Run up to `n` instructions, or until something stops execution.
+    fn run(&mut self, n: Option<usize>) -> Vec<String> {
+        let mut out = vec![];
+        let mut steps = 0;
+        while n.map_or(true, |n| steps < n) {
+            steps += 1;
+            if let Some(stop) = self.step(&mut out) {
+                out.push(stop);
+                break;
+            }
+        }
+        if !self.halted {
+            out.push(self.rt.current().to_string());
+        }
+        out
+    }
+
+    fn mem(&self, addr: u64, n: u64) -> Result<Vec<String>, String> {
+        let end = addr
+            .checked_add(n)
+            .ok_or_else(|| format!("{} cells from {} runs past the end of memory", n, addr))?;
+        Ok((addr..end)
+            .step_by(MEM_WIDTH as usize)
+            .map(|row| {
+                let vals = (row..row.saturating_add(MEM_WIDTH).min(end))
+                    .map(|ptr| self.rt[ptr].to_string())
+                    .collect::<Vec<_>>();
+                format!("{:>4}: {}", row, vals.join(" "))
+            })
+            .collect())
+    }
+
+    // Run a debugger command, returning what to print or `None` to quit.
+    fn command(&mut self, line: &str) -> Result<Option<Vec<String>>, String> {
+        let args = line.split_whitespace().collect::<Vec<_>>();
+        let out = match args[..] {
+            ["s" | "step"] => self.run(Some(1)),
+            ["s" | "step", n] => self.run(Some(num(n)?)),
+            ["c" | "continue"] => self.run(None),
+            ["b" | "break", "op", op] => {
+                if !intcode::mnemonics().any(|name| name == op) {
+                    let names = intcode::mnemonics().collect::<Vec<_>>();
+                    return Err(format!(
+                        "Unknown opcode {} (one of {})",
+                        op,
+                        names.join(", ")
+                    ));
+                }
+                self.add_break(Break::Op(op.into()))
+            }
+            ["b" | "break", "out"] => self.add_break(Break::Output),
+            ["b" | "break", addr] => self.add_break(Break::Addr(num(addr)?)),
+            ["w" | "watch", addr] => {
+                let addr = num(addr)?;
+                self.add_break(Break::Watch(addr, self.rt[addr]))
+            }
+            ["d" | "delete", n] => {
+                let n = num::<usize>(n)?;
+                if n == 0 || n > self.breaks.len() {
+                    return Err(format!("No breakpoint {}", n));
+                }
+                vec![format!("deleted {}", self.breaks.remove(n - 1))]
+            }
+            ["i" | "info"] => self
+                .breaks
+                .iter()
+                .enumerate()
+                .map(|(idx, b)| format!("{}: {}", idx + 1, b))
+                .collect(),
+            ["r" | "regs"] => vec![
+                format!(
+                    "ip {}  rb {}  input queued {}",
+                    self.rt.ptr(),
+                    self.rt.base(),
+                    self.rt.pending().len()
+                ),
+                self.rt.current().to_string(),
+            ],
+            ["m" | "mem", addr] => self.mem(num(addr)?, MEM_WIDTH)?,
+            ["m" | "mem", addr, n] => self.mem(num(addr)?, num(n)?)?,
+            ["set", addr, val] => {
+                let (addr, val) = (num(addr)?, num(val)?);
+                self.rt.set(addr, val);
+                vec![format!("[{}] = {}", addr, val)]
+            }
+            ["input", ref vals @ ..] if !vals.is_empty() => {
+                let vals = vals
+                    .iter()
+                    .map(|val| num(val))
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.rt.read_next(&vals);
+                vec![format!("{} value(s) queued", self.rt.pending().len())]
+            }
+            ["h" | "help"] => HELP.lines().map(String::from).collect(),
+            ["q" | "quit"] => return Ok(None),
+            _ => return Err(format!("Unknown command {:?} (try `help`)", line)),
+        };
+        Ok(Some(out))
+    }
+
+    fn add_break(&mut self, b: Break) -> Vec<String> {
+        self.breaks.push(b);
+        vec![format!(
+            "{}: {}",
+            self.breaks.len(),
+            self.breaks[self.breaks.len() - 1]
+        )]
+    }
+}
+
+pub fn debug(code: &str) -> Result<u8, Error> {
+    let mut dbg = Debugger::new(&code.parse()?);
+    println!("{}", dbg.rt.current());
+    let stdin = io::stdin();
+    let mut last = String::new();
+    loop {
+        print!("(debug) ");
+        io::stdout()
+            .flush()
+            .map_err(|err| Error::runtime(format!("Failed to write stdout: {}", err)))?;
+        let mut line = String::new();
+        if stdin
+            .lock()
+            .read_line(&mut line)
+            .map_err(|err| Error::runtime(format!("Failed to read stdin: {}", err)))?
+            == 0
+        {
+            println!();
+            return Ok(0);
+        }
+        if !line.trim().is_empty() {
+            last = line.trim().into();
+        }
+        if last.is_empty() {
+            continue;
+        }
+        match dbg.command(&last) {
+            Ok(Some(out)) => {
+                for line in out {
+                    println!("{}", line);
+                }
+            }
+            Ok(None) => return Ok(0),
+            Err(err) => eprintln!("{}", err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn debugger() -> Debugger {
+        let src = include_str!("../tests/fixtures/countdown.s");
+        Debugger::new(&Intcode::assemble(src).unwrap())
+    }
+
+    fn run(dbg: &mut Debugger, cmd: &str) -> Vec<String> {
+        dbg.command(cmd).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_step() {
+        let mut dbg = debugger();
+        assert_eq!(
+            run(&mut dbg, "step"),
+            ["waiting for input (use `input VAL...`)", "   0: in [12]"]
+        );
+        assert_eq!(run(&mut dbg, "input 2"), ["1 value(s) queued"]);
+        assert_eq!(
+            run(&mut dbg, "step 2"),
+            ["output: 2", "   4: add [12], #-1, [12]"]
+        );
+        assert_eq!(
+            run(&mut dbg, "regs"),
+            ["ip 4  rb 0  input queued 0", "   4: add [12], #-1, [12]"]
+        );
+        assert_eq!(run(&mut dbg, "continue"), ["output: 1", "program halted"]);
+        assert_eq!(run(&mut dbg, "step"), ["program has halted"]);
+        assert_eq!(run(&mut dbg, "mem 11 2"), ["  11: 99 0"]);
+    }
+
+    #[test]
+    fn test_breaks() {
+        let mut dbg = debugger();
+        run(&mut dbg, "input 3");
+        assert_eq!(run(&mut dbg, "break out"), ["1: break on output"]);
+        assert_eq!(run(&mut dbg, "break op jnz"), ["2: break on jnz"]);
+        assert_eq!(run(&mut dbg, "watch 12"), ["3: watch [12] = 0"]);
+        assert_eq!(
+            run(&mut dbg, "continue"),
+            ["watchpoint 3: [12] 0 -> 3", "   2: out [12]"]
+        );
+        assert_eq!(
+            run(&mut dbg, "c"),
+            [
+                "output: 3",
+                "breakpoint 1: output 3",
+                "   4: add [12], #-1, [12]"
+            ]
+        );
+        assert_eq!(
+            run(&mut dbg, "c"),
+            ["watchpoint 3: [12] 3 -> 2", "   8: jnz [12], #2"]
+        );
+        assert_eq!(run(&mut dbg, "delete 3"), ["deleted watch [12] = 2"]);
+        assert_eq!(run(&mut dbg, "delete 2"), ["deleted break on jnz"]);
+        assert_eq!(run(&mut dbg, "break 11"), ["2: break at 11"]);
+        assert_eq!(
+            run(&mut dbg, "info"),
+            ["1: break on output", "2: break at 11"]
+        );
+        run(&mut dbg, "delete 1");
+        assert_eq!(
+            run(&mut dbg, "c"),
+            [
+                "output: 2",
+                "output: 1",
+                "breakpoint 1: address 11",
+                "  11: hlt"
+            ]
+        );
+        assert!(dbg.command("delete 5").is_err());
+        assert_eq!(
+            dbg.command("break op frob"),
+            Err("Unknown opcode frob (one of add, mul, in, out, jnz, jz, lt, eq, arb, hlt)".into())
+        );
+        assert!(dbg.command("frobnicate").is_err());
+        assert_eq!(dbg.command("quit"), Ok(None));
+    }
+
+    #[test]
+    fn test_break_on_current() {
+        let mut dbg = debugger();
+        run(&mut dbg, "input 2");
+        assert_eq!(run(&mut dbg, "break 0"), ["1: break at 0"]);
+        assert_eq!(
+            run(&mut dbg, "continue"),
+            ["breakpoint 1: address 0", "   0: in [12]"]
+        );
+        assert_eq!(run(&mut dbg, "step"), ["   2: out [12]"]);
+        assert_eq!(run(&mut dbg, "break 2"), ["2: break at 2"]);
+        assert_eq!(
+            run(&mut dbg, "continue"),
+            ["breakpoint 2: address 2", "   2: out [12]"]
+        );
+        assert_eq!(
+            run(&mut dbg, "continue"),
+            ["output: 2", "breakpoint 2: address 2", "   2: out [12]"]
+        );
+    }
+
+    #[test]
+    fn test_mem() {
+        let mut dbg = debugger();
+        assert_eq!(run(&mut dbg, "mem 10 3"), ["  10: 2 99 0"]);
+        assert_eq!(
+            dbg.command("mem 18446744073709551615 2"),
+            Err("2 cells from 18446744073709551615 runs past the end of memory".into())
+        );
+        assert_eq!(
+            run(&mut dbg, "mem 18446744073709551614 1"),
+            ["18446744073709551614: 0"]
+        );
+    }
+}
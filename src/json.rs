@@ -0,0 +1,153 @@
+use std::fmt::Write;
+
+use crate::error::Error;
+use crate::runner::{Report, Status::*};
+use crate::solution::Answer;
+
+pub fn string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", u32::from(c)).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+pub fn object(fields: &[(&str, String)]) -> String {
+    let fields = fields
+        .iter()
+        .map(|(key, val)| format!("{}:{}", string(key), val))
+        .collect::<Vec<_>>();
+    format!("{{{}}}", fields.join(","))
+}
+
+pub fn array(items: &[String]) -> String {
+    format!("[{}]", items.join(","))
+}
+
+pub fn answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Num(n) => n.to_string(),
+        Answer::Text(s) => string(s),
+        Answer::Image(rows) => array(&rows.iter().map(|row| string(row)).collect::<Vec<_>>()),
+    }
+}
+
+pub fn error(err: &Error) -> String {
+    match err {
+        Error::Parse {
+            line,
+            col,
+            text,
+            msg,
+        } => object(&[
+            ("kind", string("parse")),
+            ("line", line.to_string()),
+            ("col", col.to_string()),
+            ("text", string(text)),
+            ("message", string(msg)),
+        ]),
+        Error::Vm { ip, msg } => object(&[
+            ("kind", string("vm")),
+            ("ip", ip.to_string()),
+            ("message", string(msg)),
+        ]),
+        Error::NoSolution(msg) => {
+            object(&[("kind", string("no_solution")), ("message", string(msg))])
+        }
+        Error::Unimplemented => object(&[("kind", string("unimplemented"))]),
+        Error::Runtime(msg) => object(&[("kind", string("runtime")), ("message", string(msg))]),
+    }
+}
+
+pub fn report(report: &Report) -> String {
+    let mut fields = vec![
+        ("year", report.year.to_string()),
+        ("day", report.day.to_string()),
+        ("time_ns", report.time.as_nanos().to_string()),
+    ];
+    match &report.status {
+        Solved(outcome) => {
+            let parts = outcome
+                .answers
+                .iter()
+                .map(|(part, ans, time)| {
+                    object(&[
+                        ("part", part.to_string()),
+                        ("answer", answer(ans)),
+                        ("time_ns", time.as_nanos().to_string()),
+                    ])
+                })
+                .collect::<Vec<_>>();
+            fields.push(("status", string("ok")));
+            fields.push(("parse_ns", outcome.parse.as_nanos().to_string()));
+            fields.push(("parts", array(&parts)));
+        }
+        Failed(err) => {
+            fields.push(("status", string("failed")));
+            fields.push(("error", error(err)));
+        }
+        Unimplemented => fields.push(("status", string("unimplemented"))),
+        NoInput => fields.push(("status", string("no_input"))),
+        Panicked(msg) => {
+            fields.push(("status", string("panicked")));
+            fields.push((
+                "error",
+                object(&[("kind", string("panic")), ("message", string(msg))]),
+            ));
+        }
+        TimedOut(timeout) => {
+            fields.push(("status", string("timed_out")));
+            fields.push(("timeout_ns", timeout.as_nanos().to_string()));
+        }
+    }
+    object(&fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("abc"), "\"abc\"");
+        assert_eq!(string("a\"b\\c\nd"), "\"a\\\"b\\\\c\\nd\"");
+        assert_eq!(string("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn test_object() {
+        assert_eq!(
+            object(&[("year", "19".into()), ("status", string("ok"))]),
+            "{\"year\":19,\"status\":\"ok\"}"
+        );
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(answer(&Answer::from(-42)), "-42");
+        assert_eq!(answer(&Answer::from("abc")), "\"abc\"");
+        assert_eq!(answer(&Answer::image("# #\n # ")), "[\"# #\",\" # \"]");
+    }
+
+    #[test]
+    fn test_error() {
+        assert_eq!(
+            error(&Error::parse(2, 3, "1,x", "Invalid value")),
+            "{\"kind\":\"parse\",\"line\":2,\"col\":3,\"text\":\"1,x\",\"message\":\"Invalid value\"}"
+        );
+        assert_eq!(
+            error(&Error::vm(4, "Invalid opcode 42")),
+            "{\"kind\":\"vm\",\"ip\":4,\"message\":\"Invalid opcode 42\"}"
+        );
+    }
+}
@@ -0,0 +1,80 @@
+#![warn(deprecated_in_future)]
+#![warn(future_incompatible)]
+#![warn(nonstandard_style)]
+#![warn(rust_2018_compatibility)]
+#![warn(rust_2018_idioms)]
+#![warn(trivial_casts, trivial_numeric_casts)]
+#![warn(unused)]
+#![warn(clippy::all, clippy::pedantic)]
+#![warn(clippy::missing_const_for_fn)]
+#![warn(clippy::use_self)]
+#![warn(clippy::if_then_some_else_none)]
+#![allow(clippy::enum_glob_use)]
+#![allow(clippy::if_not_else)]
+#![allow(clippy::missing_errors_doc)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::must_use_candidate)]
+#![allow(clippy::similar_names)]
+#![allow(clippy::too_many_lines)]
+
+use runner::Day;
+
+macro_rules! days {
+    ($($d:ident),*) => {
+        [$(($d::SOLVER, $d::INPUT),)*]
+    }
+}
+
+macro_rules! years {
+    ($($y:ident),*) => {
+        [$(($y::YEAR, $y::DAYS, $y::ANSWERS),)*]
+    }
+}
+
+pub mod error;
+pub mod json;
+pub mod runner;
+pub mod solution;
+pub mod verify;
+pub mod y19;
+pub mod y20;
+pub mod y21;
+
+pub static YEARS: &[(usize, &[Day], &str)] = &years!(y19, y20, y21);
+
+pub fn solutions() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|(_, days, _)| days.iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+
+    #[test]
+    fn test_registry() {
+        for (year, days, _) in YEARS {
+            for (run, _) in *days {
+                assert_eq!(run.year(), *year);
+                assert!(!run.title().is_empty());
+            }
+            assert!(days.windows(2).all(|w| w[0].0.day() < w[1].0.day()));
+        }
+        assert!(YEARS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(
+            solutions().count(),
+            YEARS.iter().map(|(_, days, _)| days.len()).sum()
+        );
+    }
+
+    #[test]
+    fn test_parts() {
+        for (run, _) in solutions() {
+            let input = run.examples().first().map_or("", |example| example.input);
+            for part in Part::BOTH.iter().filter(|part| !run.parts().contains(part)) {
+                let report = runner::run_day(run.year(), run.day(), Some(input), &[*part]).unwrap();
+                assert_eq!(report.status, runner::Status::Unimplemented);
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::fs;
+use std::path::Path;
+
+use aoc::error::Error;
+use aoc::runner;
+use aoc::solution::Part;
+use aoc::verify;
+
+use crate::scaffold;
+use crate::table::{self, Align::*};
+
+fn status(parts: &[Part]) -> &'static str {
+    match parts {
+        [] => "stub",
+        [part] if *part == Part::One => "part 1",
+        [_] => "part 2",
+        _ => "both",
+    }
+}
+
+// Count the unit tests in a day's module, if its source is around.
+fn tests(src: Option<&Path>, year: usize, day: usize) -> Option<usize> {
+    let path = src?
+        .join(format!("y{}", year))
+        .join(format!("d{:02}.rs", day));
+    let src = fs::read_to_string(path).ok()?;
+    Some(src.lines().filter(|line| line.trim() == "#[test]").count())
+}
+
+pub fn run(year: Option<usize>) -> Result<u8, Error> {
+    let src = scaffold::src_dir().ok();
+    let mut rows = vec![];
+    let mut summary: Vec<(usize, [usize; 6])> = vec![];
+    for (year, day) in runner::all_days(year)? {
+        let (run, _) = runner::find_day(year, day)?;
+        let answers = verify::expected_for(year, day)?
+            .iter()
+            .map(|expected| expected.part.to_string())
+            .collect::<Vec<_>>();
+        let tests = tests(src.as_deref(), year, day);
+        rows.push([
+            year.to_string(),
+            day.to_string(),
+            run.title().into(),
+            status(run.parts()).into(),
+            run.examples().len().to_string(),
+            if answers.is_empty() {
+                "-".into()
+            } else {
+                answers.join(",")
+            },
+            tests.map_or_else(|| "?".into(), |tests| tests.to_string()),
+        ]);
+
+        if summary.last().is_none_or(|(y, _)| *y != year) {
+            summary.push((year, [0; 6]));
+        }
+        let counts = &mut summary.last_mut().unwrap().1;
+        counts[run.parts().len()] += 1;
+        counts[3] += usize::from(!run.examples().is_empty());
+        counts[4] += usize::from(!answers.is_empty());
+        counts[5] += usize::from(tests.unwrap_or(0) > 0);
+    }
+
+    print!(
+        "{}",
+        table::render(
+            ["Year", "Day", "Title", "Status", "Examples", "Answers", "Tests"],
+            [Right, Right, Left, Left, Right, Left, Right],
+            &rows
+        )
+    );
+    println!();
+    for (year, [stub, partial, both, examples, answers, tests]) in summary {
+        println!(
+            "{}: {} solved, {} partial, {} stubs; {} with examples, {} with answers, {} with tests",
+            year, both, partial, stub, examples, answers, tests
+        );
+    }
+    Ok(0)
+}
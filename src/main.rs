@@ -15,36 +15,465 @@
 #![allow(clippy::too_many_lines)]
 
 use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
 
-macro_rules! days {
-    ($($d:ident),*) => {
-        [$(Box::new($d::run),)*]
+use aoc::error::Error;
+use aoc::json;
+use aoc::runner::{self, Report, Status::*};
+use aoc::solution::{Answer, Part};
+use aoc::verify::{self, Verdict};
+use aoc::y19::{self, intcode::Intcode};
+use table::Align::{Left, Right};
+
+mod bench;
+mod intcode;
+mod list;
+mod scaffold;
+mod table;
+
+const EXIT_ERROR: u8 = 1;
+
+fn print_table(reports: &[Report]) {
+    let fmt_answer = |answer: Option<&Answer>| match answer {
+        Some(Answer::Image(rows)) => format!("<{} rows>", rows.len()),
+        Some(answer) => answer.to_string(),
+        None => String::new(),
+    };
+    let rows = reports
+        .iter()
+        .map(|report| {
+            [
+                format!("{}", report.year),
+                format!("{}", report.day),
+                fmt_answer(report.answer(Part::One)),
+                fmt_answer(report.answer(Part::Two)),
+                format!("{:.3?}", report.time),
+                report.status(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    print!(
+        "{}",
+        table::render(
+            ["Year", "Day", "Part 1", "Part 2", "Time", "Status"],
+            [Right, Right, Left, Left, Right, Left],
+            &rows
+        )
+    );
+}
+
+fn run_all(days: &[(usize, usize)], opts: &Options) -> Result<u8, Error> {
+    let reports = runner::run_days(days, None, opts.parts(), opts.jobs(), opts.timeout)?;
+    if opts.format == Format::Json {
+        for report in &reports {
+            println!("{}", json::report(report));
+        }
+    } else {
+        print_table(&reports);
+    }
+
+    let failed = reports
+        .iter()
+        .filter(|report| matches!(report.status, Failed(_) | Panicked(_) | TimedOut(_)))
+        .count();
+    if failed > 0 {
+        eprintln!("{} day(s) failed", failed);
+    }
+    Ok(reports
+        .iter()
+        .filter(|report| !report.status.skipped())
+        .map(|report| report.status.exit_code())
+        .max()
+        .unwrap_or(0))
+}
+
+fn read_input(path: &str) -> Result<String, Error> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map(|_| input)
+            .map_err(|err| Error::runtime(format!("Failed to read stdin: {}", err)))
+    } else {
+        fs::read_to_string(path)
+            .map_err(|err| Error::runtime(format!("Failed to read {}: {}", path, err)))
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Default, Copy, Clone)]
+enum Format {
+    #[default]
+    Text,
+    Csv,
+    Json,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "text" => Ok(Self::Text),
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("Invalid format: {}", format)),
+        }
     }
 }
 
-macro_rules! match_year {
-    ($year:expr, $day:expr, $($y:ident),*) => {
-        match $year {
-            $($y::YEAR if 1 <= $day && $day <= $y::DAYS.len() => $y::DAYS[$day - 1](),)*
-            _ => Err("Year or day out of range".into()),
+// Ways of running a day other than solving it.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+enum Live {
+    Interactive,
+    Watch,
+    Map,
+}
+
+#[derive(PartialEq, Eq, Default, Debug)]
+struct Options {
+    input: Option<String>,
+    example: Option<usize>,
+    part: Option<Part>,
+    bench: Option<usize>,
+    format: Format,
+    jobs: Option<usize>,
+    timeout: Option<Duration>,
+    live: Option<Live>,
+}
+
+impl Options {
+    fn parts(&self) -> &[Part] {
+        match self.part {
+            Some(Part::One) => &Part::BOTH[..1],
+            Some(Part::Two) => &Part::BOTH[1..],
+            None => &Part::BOTH,
+        }
+    }
+
+    fn jobs(&self) -> usize {
+        self.jobs
+            .or_else(|| thread::available_parallelism().ok().map(usize::from))
+            .unwrap_or(1)
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<(Vec<&str>, Options), Error> {
+    let mut pos = vec![];
+    let mut opts = Options::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| Error::runtime(format!("Missing value for {}", arg)))
+        };
+        match arg.as_str() {
+            "--input" => opts.input = Some(read_input(value()?)?),
+            "--example" => {
+                opts.example = Some(
+                    value()?
+                        .parse()
+                        .map_err(|_| Error::runtime("Invalid example number"))?,
+                );
+            }
+            "--part" => opts.part = Some(value()?.parse().map_err(Error::runtime)?),
+            "--bench" => {
+                opts.bench = Some(
+                    value()?
+                        .parse()
+                        .map_err(|_| Error::runtime("Invalid number of runs"))?,
+                );
+            }
+            "--format" => opts.format = value()?.parse().map_err(Error::runtime)?,
+            flag @ ("--interactive" | "--watch" | "--map") => {
+                let live = match flag {
+                    "--interactive" => Live::Interactive,
+                    "--watch" => Live::Watch,
+                    _ => Live::Map,
+                };
+                if opts.live.replace(live).is_some() {
+                    return Err(Error::runtime(
+                        "Only one of --interactive, --watch and --map can be given",
+                    ));
+                }
+            }
+            "--jobs" => {
+                opts.jobs = Some(
+                    value()?
+                        .parse()
+                        .ok()
+                        .filter(|jobs| *jobs > 0)
+                        .ok_or_else(|| Error::runtime("Invalid number of jobs"))?,
+                );
+            }
+            "--timeout" => {
+                opts.timeout = Some(
+                    value()?
+                        .parse()
+                        .ok()
+                        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                        .ok_or_else(|| Error::runtime("Invalid timeout"))?,
+                );
+            }
+            flag if flag.starts_with("--") => {
+                return Err(Error::runtime(format!("Unknown option {}", flag)))
+            }
+            arg => pos.push(arg),
+        }
+    }
+    Ok((pos, opts))
+}
+
+fn parse_year(year: &str) -> Result<usize, Error> {
+    year.parse().map_err(|_| Error::runtime("Invalid year"))
+}
+
+fn parse_day(day: &str) -> Result<usize, Error> {
+    day.parse().map_err(|_| Error::runtime("Invalid day"))
+}
+
+fn run_one(year: usize, day: usize, opts: &Options) -> Result<u8, Error> {
+    let report = runner::run_days(
+        &[(year, day)],
+        opts.input.as_deref(),
+        opts.parts(),
+        1,
+        opts.timeout,
+    )?
+    .remove(0);
+    if opts.format == Format::Json {
+        println!("{}", json::report(&report));
+        return Ok(report.status.exit_code());
+    }
+    let example = opts
+        .example
+        .map(|n| verify::find_example(year, day, n))
+        .transpose()?;
+    let mut code = report.status.exit_code();
+    match &report.status {
+        Solved(outcome) => {
+            for (part, answer, _) in &outcome.answers {
+                match example.and_then(|example| example.expected(*part)) {
+                    Some(expected) => {
+                        if !verify::matches(answer, expected) {
+                            code = verify::EXIT_MISMATCH;
+                        }
+                        if answer.to_string().contains('\n') || expected.contains('\n') {
+                            println!("{}\n(expected)\n{}", answer, expected);
+                        } else {
+                            println!("{} (expected {})", answer, expected);
+                        }
+                    }
+                    None => println!("{}", answer),
+                }
+            }
         }
+        Failed(err) => eprintln!("error: {}", err.render()),
+        _ => eprintln!("{}", report.status()),
+    }
+    Ok(code)
+}
+
+fn run_verify(days: &[(usize, usize)]) -> Result<u8, Error> {
+    let mut code = 0;
+    let (mut correct, mut wrong, mut unrecorded) = (0, 0, 0);
+    for (year, day) in days {
+        let verdict = verify::verify_day(*year, *day)?;
+        let msg = match &verdict {
+            Verdict::Correct => {
+                correct += 1;
+                "ok".into()
+            }
+            Verdict::Wrong(errs) => {
+                wrong += 1;
+                errs.join("; ")
+            }
+            Verdict::Unrecorded => {
+                unrecorded += 1;
+                "no recorded answers".into()
+            }
+            Verdict::Skipped(status) => format!("skipped ({})", status),
+        };
+        println!("{:>4}  {:>3}  {}", year, day, msg);
+        code = code.max(verdict.exit_code());
     }
+    println!(
+        "{} correct, {} wrong, {} without recorded answers",
+        correct, wrong, unrecorded
+    );
+    Ok(code)
 }
 
-mod y19;
-mod y20;
-mod y21;
+// Play, watch or map a day instead of just solving it.
+fn run_live((year, day): (usize, usize), input: Option<&str>, live: Live) -> Result<u8, Error> {
+    let input = match input {
+        Some(input) => input,
+        None => runner::find_day(year, day)?.1,
+    };
+    let prog = || input.parse::<Intcode>();
+    let res = match (year, day, live) {
+        (19, 13, Live::Watch) => {
+            y19::d13::watch(&prog()?, io::stdout()).map(|score| println!("Final score: {}", score))
+        }
+        (19, 15, Live::Map) => y19::d15::map(&prog()?, io::stdout()),
+        (19, 25, Live::Map) => y19::d25::map(&prog()?, io::stdout()),
+        (19, 25, Live::Interactive) => y19::d25::play(&prog()?, io::stdin().lock(), io::stdout()),
+        (_, _, Live::Interactive) => {
+            return Err(Error::runtime(format!(
+                "{} {} has no interactive mode",
+                year, day
+            )))
+        }
+        (_, _, Live::Watch) => {
+            return Err(Error::runtime(format!(
+                "{} {} cannot be watched",
+                year, day
+            )))
+        }
+        (_, _, Live::Map) => return Err(Error::runtime(format!("{} {} has no map", year, day))),
+    };
+    res.map(|()| 0)
+}
 
-type Run = dyn Fn() -> Result<String, String> + Sync;
+fn run_new(year: usize, day: Option<usize>) -> Result<u8, Error> {
+    for path in scaffold::new(&scaffold::src_dir()?, year, day)? {
+        println!("created {}", path.display());
+    }
+    Ok(0)
+}
 
-fn main() -> Result<(), String> {
+fn run(args: &[String]) -> Result<u8, Error> {
+    let (pos, mut opts) = parse_args(&args[1..])?;
+    let (days, all) = match pos[..] {
+        ["new", ..] if opts != Options::default() => return Err(Error::runtime("new takes no options")),
+        ["new", year] => return run_new(parse_year(year)?, None),
+        ["new", year, day] => return run_new(parse_year(year)?, Some(parse_day(day)?)),
+        ["intcode", ..] if opts != Options::default() => {
+            return Err(Error::runtime("intcode takes no options"))
+        }
+        ["intcode", "debug", "-"] => return Err(Error::runtime("debug reads commands from stdin")),
+        ["intcode", "debug", path] => return intcode::debug(&read_input(path)?),
+        ["intcode", "asm", path] => return intcode::asm(&read_input(path)?),
+        ["intcode", "disasm", path] => return intcode::disasm(&read_input(path)?),
+        ["intcode", "trace", path, out, ref input @ ..] => {
+            return intcode::trace(&read_input(path)?, out, input)
+        }
+        ["intcode", "profile", path, out, ref input @ ..] => {
+            return intcode::profile(&read_input(path)?, out, input)
+        }
+        ["list", ..] if opts != Options::default() => return Err(Error::runtime("list takes no options")),
+        ["list"] => return list::run(None),
+        ["list", year] => return list::run(Some(parse_year(year)?)),
+        ["verify", ..] if opts != Options::default() => return Err(Error::runtime("verify takes no options")),
+        ["verify"] => return run_verify(&runner::all_days(None)?),
+        ["verify", year] => return run_verify(&runner::all_days(Some(parse_year(year)?))?),
+        ["verify", year, day] => return run_verify(&[(parse_year(year)?, parse_day(day)?)]),
+        ["all"] | [_, "all"] if opts.input.is_some() || opts.example.is_some() => {
+            return Err(Error::runtime("--input and --example run a single day, not all"))
+        }
+        ["all"] => (runner::all_days(None)?, true),
+        [year, "all"] => (runner::all_days(Some(parse_year(year)?))?, true),
+        [year, day] => (vec![(parse_year(year)?, parse_day(day)?)], false),
+        _ => {
+            return Err(Error::runtime(format!(
+                "Usage: {0} YEAR DAY [--input PATH|-|--example N] [--part 1|2] [--format text|json] [--timeout SECS | --bench N [--format text|csv|json]]\n       {0} YEAR DAY --interactive|--watch|--map [--input PATH]\n       {0} [YEAR] all [--part 1|2] [--format text|json] [--jobs N] [--timeout SECS | --bench N [--format text|csv|json]]\n       {0} list [YEAR]\n       {0} intcode asm|disasm FILE|-\n       {0} intcode debug FILE\n       {0} intcode trace|profile FILE|- OUT [INPUT...]\n       {0} verify [YEAR [DAY]]\n       {0} new YEAR [DAY]",
+                args[0]
+            )))
+        }
+    };
+    if let Some(n) = opts.example {
+        if opts.input.is_some() {
+            return Err(Error::runtime("--example cannot be combined with --input"));
+        }
+        let example = verify::find_example(days[0].0, days[0].1, n)?;
+        opts.input = Some(example.input.into());
+        if let (None, [part]) = (opts.part, &example.parts()[..]) {
+            opts.part = Some(*part);
+        }
+    }
+    if opts.jobs.is_some() && !all {
+        return Err(Error::runtime("--jobs only applies when running all days"));
+    }
+    if opts.bench.is_some() && (opts.jobs.is_some() || opts.timeout.is_some()) {
+        return Err(Error::runtime(
+            "--bench runs one day at a time and takes no --jobs or --timeout",
+        ));
+    }
+    if let Some(live) = opts.live {
+        if all {
+            return Err(Error::runtime(
+                "--interactive, --watch and --map run a single day",
+            ));
+        }
+        if opts.timeout.is_some() {
+            return Err(Error::runtime(
+                "--interactive, --watch and --map take no --timeout",
+            ));
+        }
+        return run_live(days[0], opts.input.as_deref(), live);
+    }
+    match opts.bench {
+        Some(runs) => bench::run(
+            &days,
+            runs,
+            opts.input.as_deref(),
+            opts.parts(),
+            opts.format,
+        ),
+        None if opts.format == Format::Csv => Err(Error::runtime("--format csv requires --bench")),
+        None if all => run_all(&days, &opts),
+        None => run_one(days[0].0, days[0].1, &opts),
+    }
+}
+
+fn main() -> ExitCode {
+    runner::install_panic_hook();
     let args: Vec<String> = env::args().collect();
-    if args.len() != 3 {
-        return Err(format!("Usage: {} YEAR DAY", args[0]));
+    match run(&args) {
+        Ok(code) => ExitCode::from(code),
+        Err(err) => {
+            eprintln!("Error: {}", err.render());
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_all_with_input() {
+        let err = Err(Error::runtime(
+            "--input and --example run a single day, not all",
+        ));
+        assert_eq!(run(&args("aoc 21 all --example 1")), err);
+        assert_eq!(run(&args("aoc all --input Cargo.toml")), err);
     }
-    let year = args[1].parse::<usize>().map_err(|_| "Invalid year")?;
-    let day = args[2].parse::<usize>().map_err(|_| "Invalid day")?;
 
-    println!("{}", match_year!(year, day, y19, y20, y21)?);
-    Ok(())
+    #[test]
+    fn test_ignored_limits() {
+        assert_eq!(
+            run(&args("aoc 21 1 --jobs 2")),
+            Err(Error::runtime("--jobs only applies when running all days"))
+        );
+        let err = Err(Error::runtime(
+            "--bench runs one day at a time and takes no --jobs or --timeout",
+        ));
+        assert_eq!(run(&args("aoc 21 all --bench 3 --jobs 2")), err);
+        assert_eq!(run(&args("aoc 21 1 --bench 3 --timeout 5")), err);
+        assert_eq!(
+            run(&args("aoc 19 25 --interactive --timeout 5")),
+            Err(Error::runtime(
+                "--interactive, --watch and --map take no --timeout"
+            ))
+        );
+    }
 }
@@ -0,0 +1,286 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::Error;
+use crate::solution::{Answer, Outcome, Part, Run};
+use crate::YEARS;
+
+pub type Day = (&'static dyn Run, &'static str);
+
+// Match the main thread so deeply recursive solutions behave the same.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Status {
+    Solved(Outcome),
+    Failed(Error),
+    Unimplemented,
+    NoInput,
+    Panicked(String),
+    TimedOut(Duration),
+}
+use Status::*;
+
+impl Status {
+    pub const fn exit_code(&self) -> u8 {
+        match self {
+            Solved(_) => 0,
+            Failed(_) => 2,
+            Unimplemented => 3,
+            Panicked(_) => 4,
+            TimedOut(_) => 6,
+            NoInput => 7,
+        }
+    }
+
+    // Whether the day couldn't be run at all, as opposed to having failed.
+    pub const fn skipped(&self) -> bool {
+        matches!(self, Unimplemented | NoInput)
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub year: usize,
+    pub day: usize,
+    pub status: Status,
+    pub time: Duration,
+}
+
+impl Report {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        match &self.status {
+            Solved(outcome) => outcome
+                .answers
+                .iter()
+                .find_map(|(p, answer, _)| (*p == part).then_some(answer)),
+            Failed(_) | Unimplemented | NoInput | Panicked(_) | TimedOut(_) => None,
+        }
+    }
+
+    pub fn status(&self) -> String {
+        match &self.status {
+            Solved(_) => "ok".into(),
+            Failed(err) => format!("error: {}", err),
+            Unimplemented => "not implemented".into(),
+            NoInput => "no puzzle input".into(),
+            Panicked(msg) => format!("panicked: {}", msg),
+            TimedOut(timeout) => format!("timed out after {:?}", timeout),
+        }
+    }
+}
+
+pub fn install_panic_hook() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if CATCHING.with(Cell::get) {
+            let loc = info
+                .location()
+                .map(|loc| format!("{}:{}:{}", loc.file(), loc.line(), loc.column()));
+            PANIC_LOCATION.with(|l| *l.borrow_mut() = loc);
+        } else {
+            hook(info);
+        }
+    }));
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>")
+}
+
+fn catch_panic(run: &dyn Run, input: &str, parts: &[Part]) -> Status {
+    CATCHING.with(|c| c.set(true));
+    let res = panic::catch_unwind(AssertUnwindSafe(|| run.run(input, parts)));
+    CATCHING.with(|c| c.set(false));
+    match res {
+        Ok(Ok(outcome)) => Solved(outcome),
+        Ok(Err(Error::Unimplemented)) => Unimplemented,
+        Ok(Err(err)) => Failed(err),
+        Err(payload) => {
+            let msg = panic_message(&*payload);
+            if msg.starts_with("not yet implemented") {
+                Unimplemented
+            } else if let Some(loc) = PANIC_LOCATION.with(RefCell::take) {
+                Panicked(format!("{} at {}", msg, loc))
+            } else {
+                Panicked(msg.into())
+            }
+        }
+    }
+}
+
+pub fn find_day(year: usize, day: usize) -> Result<&'static Day, Error> {
+    YEARS
+        .iter()
+        .find(|(y, _, _)| *y == year)
+        .and_then(|(_, days, _)| days.iter().find(|(run, _)| run.day() == day))
+        .ok_or_else(|| Error::runtime("Year or day out of range"))
+}
+
+pub fn all_days(year: Option<usize>) -> Result<Vec<(usize, usize)>, Error> {
+    let days = YEARS
+        .iter()
+        .filter(|(y, _, _)| year.is_none_or(|year| *y == year))
+        .flat_map(|(year, days, _)| days.iter().map(move |(run, _)| (*year, run.day())))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        return Err(Error::runtime("Year out of range"));
+    }
+    Ok(days)
+}
+
+pub fn run_day(
+    year: usize,
+    day: usize,
+    input: Option<&str>,
+    parts: &[Part],
+) -> Result<Report, Error> {
+    let (run, default) = find_day(year, day)?;
+    // Leave out stubbed parts so they don't hide the answers to solved ones,
+    // unless they are all that was asked for.
+    let solved = parts
+        .iter()
+        .copied()
+        .filter(|part| run.parts().contains(part))
+        .collect::<Vec<_>>();
+    let parts = if solved.is_empty() { parts } else { &solved };
+    let start = Instant::now();
+    // Days are checked in with an empty input until the puzzle input is added.
+    let status = match input {
+        None if default.trim().is_empty() && !solved.is_empty() => NoInput,
+        _ => catch_panic(*run, input.unwrap_or(default), parts),
+    };
+    Ok(Report {
+        year,
+        day,
+        status,
+        time: start.elapsed(),
+    })
+}
+
+fn run_timed(
+    year: usize,
+    day: usize,
+    input: Option<Arc<str>>,
+    parts: &[Part],
+    timeout: Option<Duration>,
+) -> Result<Report, Error> {
+    let Some(timeout) = timeout else {
+        return run_day(year, day, input.as_deref(), parts);
+    };
+    find_day(year, day)?;
+
+    // A runaway solver cannot be stopped, so run it on its own thread and
+    // abandon it if it takes too long.
+    let (tx, rx) = mpsc::channel();
+    let parts = parts.to_vec();
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || tx.send(run_day(year, day, input.as_deref(), &parts)))
+        .map_err(|err| Error::runtime(format!("Failed to spawn thread: {}", err)))?;
+    match rx.recv_timeout(timeout) {
+        Ok(report) => report,
+        Err(mpsc::RecvTimeoutError::Timeout) => Ok(Report {
+            year,
+            day,
+            status: TimedOut(timeout),
+            time: timeout,
+        }),
+        Err(mpsc::RecvTimeoutError::Disconnected) => Ok(Report {
+            year,
+            day,
+            status: Panicked("worker thread exited".into()),
+            time: timeout,
+        }),
+    }
+}
+
+pub fn run_days(
+    days: &[(usize, usize)],
+    input: Option<&str>,
+    parts: &[Part],
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Result<Vec<Report>, Error> {
+    let input = input.map(Arc::<str>::from);
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let (tx, next, input) = (tx.clone(), &next, &input);
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, move || loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    let (year, day) = match days.get(idx) {
+                        Some(day) => *day,
+                        None => break,
+                    };
+                    let report = run_timed(year, day, input.clone(), parts, timeout);
+                    if tx.send((idx, report)).is_err() {
+                        break;
+                    }
+                })
+                .map_err(|err| Error::runtime(format!("Failed to spawn thread: {}", err)))?;
+        }
+        Ok::<_, Error>(())
+    })?;
+    drop(tx);
+
+    let mut reports = rx.into_iter().collect::<Vec<_>>();
+    reports.sort_unstable_by_key(|(idx, _)| *idx);
+    reports.into_iter().map(|(_, report)| report).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_days() {
+        let days = [(21, 1), (19, 1), (20, 1), (19, 14)];
+        let reports = run_days(&days, None, &Part::BOTH, 3, Some(Duration::from_secs(60))).unwrap();
+        assert_eq!(
+            reports
+                .iter()
+                .map(|report| (report.year, report.day))
+                .collect::<Vec<_>>(),
+            days
+        );
+        assert!(matches!(reports[0].status, Solved(_)));
+        assert_eq!(reports[3].status, Unimplemented);
+        assert!(run_days(&[(19, 26)], None, &Part::BOTH, 1, None).is_err());
+    }
+
+    #[test]
+    fn test_partial_day() {
+        let report = run_day(19, 10, Some(".#\n##\n"), &Part::BOTH).unwrap();
+        assert!(report.answer(Part::One).is_some());
+        assert_eq!(report.answer(Part::Two), None);
+        let report = run_day(19, 10, Some(".#\n##\n"), &[Part::Two]).unwrap();
+        assert_eq!(report.status, Unimplemented);
+    }
+
+    #[test]
+    fn test_no_input() {
+        assert_eq!(crate::y19::d23::INPUT, "");
+        let report = run_day(19, 23, None, &Part::BOTH).unwrap();
+        assert_eq!(report.status, NoInput);
+        let report = run_day(19, 23, Some(""), &Part::BOTH).unwrap();
+        assert!(matches!(report.status, Failed(_)));
+    }
+}
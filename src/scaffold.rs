@@ -0,0 +1,298 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc::error::Error;
+
+const WIDTH: usize = 100;
+
+fn day_template(day: usize) -> String {
+    format!(
+        r#"use crate::error::Error;
+use crate::solution::{{Answer, Part, Run, Solution}};
+
+const fn part1() -> Result<u64, Error> {{
+    Err(Error::Unimplemented)
+}}
+
+const fn part2() -> Result<u64, Error> {{
+    Err(Error::Unimplemented)
+}}
+
+pub const INPUT: &str = include_str!("input/d{day:02}.txt");
+
+struct Solver;
+
+impl Solution for Solver {{
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = {day};
+    const TITLE: &'static str = "Day {day}";
+    const PARTS: &'static [Part] = &[];
+
+    type Input = ();
+
+    fn parse(_input: &str) -> Result<Self::Input, Error> {{
+        Ok(())
+    }}
+
+    fn part1((): &Self::Input) -> Result<Answer, Error> {{
+        Ok(part1()?.into())
+    }}
+
+    fn part2((): &Self::Input) -> Result<Answer, Error> {{
+        Ok(part2()?.into())
+    }}
+}}
+
+pub const SOLVER: &dyn Run = &Solver;
+
+#[cfg(test)]
+mod tests {{
+    // use super::*;
+
+    // #[test]
+    // fn test01() {{}}
+}}
+"#,
+        day = day
+    )
+}
+
+fn year_template(year: usize) -> String {
+    format!(
+        r#"use crate::Day;
+
+pub const YEAR: usize = {year};
+
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+pub static DAYS: &[Day] = &days!();
+"#,
+        year = year
+    )
+}
+
+const ANSWERS_TEMPLATE: &str = "# DAY PART ANSWER
+# Image answers continue on the following lines, one row per line after '|'.
+";
+
+// Format macro arguments the way rustfmt would.
+fn macro_args(name: &str, args: &[String]) -> String {
+    let line = format!("{}!({})", name, args.join(", "));
+    if line.len() + "pub static DAYS: &[Day] = &;".len() <= WIDTH {
+        return line;
+    }
+    let mut lines = vec![];
+    let mut cur = String::new();
+    for arg in args {
+        if !cur.is_empty() && 4 + cur.len() + arg.len() + 2 > WIDTH {
+            lines.push(format!("    {}", cur.trim_end()));
+            cur.clear();
+        }
+        cur.push_str(arg);
+        cur.push_str(", ");
+    }
+    lines.push(format!("    {}", cur.trim_end().trim_end_matches(',')));
+    format!("{}!(\n{}\n)", name, lines.join("\n"))
+}
+
+// Add `pub mod NAME;` and register NAME in the `MACRO!(...)` list, keeping
+// both sorted.
+fn register(src: &str, macro_name: &str, name: &str) -> Result<String, Error> {
+    let prefix = &name[..1];
+    let is_mod = |line: &str| {
+        line.strip_prefix("pub mod ")
+            .and_then(|m| m.strip_suffix(';'))
+            .is_some_and(|m| m.starts_with(prefix) && m[1..].chars().all(|c| c.is_ascii_digit()))
+    };
+    let open = format!("{}!(", macro_name);
+    let start = src
+        .find(&open)
+        .ok_or_else(|| Error::runtime(format!("Missing {}! invocation", macro_name)))?;
+    let end = start
+        + src[start..]
+            .find(')')
+            .ok_or_else(|| Error::runtime(format!("Unterminated {}! invocation", macro_name)))?;
+    let mut names = src[start + open.len()..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+        .collect::<Vec<_>>();
+    if names.iter().any(|n| n == name) {
+        return Err(Error::runtime(format!("{} is already registered", name)));
+    }
+    names.push(name.into());
+    names.sort();
+    let registered = format!(
+        "{}{}{}",
+        &src[..start],
+        macro_args(macro_name, &names),
+        &src[end + 1..]
+    );
+
+    let mut lines = registered.lines().map(String::from).collect::<Vec<_>>();
+    let decl = format!("pub mod {};", name);
+    if let Some(idx) = lines
+        .iter()
+        .position(|line| is_mod(line) && line.as_str() > decl.as_str())
+    {
+        lines.insert(idx, decl);
+    } else if let Some(idx) = lines.iter().rposition(|line| is_mod(line)) {
+        lines.insert(idx + 1, decl);
+    } else {
+        // The first module goes after the imports.
+        let idx = lines.iter().position(String::is_empty).unwrap_or(0);
+        lines.splice(idx..idx, [String::new(), decl]);
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path)
+        .map_err(|err| Error::runtime(format!("Failed to read {}: {}", path.display(), err)))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    fs::write(path, contents)
+        .map_err(|err| Error::runtime(format!("Failed to write {}: {}", path.display(), err)))
+}
+
+// The crate's `src` directory, looked up from the current directory so that
+// an installed binary works on whichever checkout it is run from.
+pub fn src_dir() -> Result<PathBuf, Error> {
+    let cwd = env::current_dir()
+        .map_err(|err| Error::runtime(format!("Failed to get current directory: {}", err)))?;
+    cwd.ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file() && dir.join("src").join("lib.rs").is_file())
+        .map(|dir| dir.join("src"))
+        .ok_or_else(|| {
+            Error::runtime(format!(
+                "No crate with a src/lib.rs found in {} or above",
+                cwd.display()
+            ))
+        })
+}
+
+// Work out every file to create or update before touching any of them, so
+// that a clash leaves the tree as it was. Returns the files created.
+pub fn new(src: &Path, year: usize, day: Option<usize>) -> Result<Vec<PathBuf>, Error> {
+    if !(10..100).contains(&year) {
+        return Err(Error::runtime("Year must have two digits"));
+    }
+    if day.is_some_and(|day| !(1..=25).contains(&day)) {
+        return Err(Error::runtime("Day out of range"));
+    }
+    let dir = src.join(format!("y{}", year));
+    let year_mod = dir.join("mod.rs");
+    let mut create = vec![];
+    let mut update = vec![];
+    let mut days = if year_mod.exists() {
+        read(&year_mod)?
+    } else {
+        let lib = src.join("lib.rs");
+        update.push((
+            lib.clone(),
+            register(&read(&lib)?, "years", &format!("y{}", year))?,
+        ));
+        create.push((dir.join("answers.txt"), ANSWERS_TEMPLATE.into()));
+        year_template(year)
+    };
+    for day in day.map_or(1..=25, |day| day..=day) {
+        let name = format!("d{:02}", day);
+        create.push((dir.join(format!("{}.rs", name)), day_template(day)));
+        let input = dir.join("input").join(format!("{}.txt", name));
+        if !input.exists() {
+            create.push((input, String::new()));
+        }
+        days = register(&days, "days", &name)?;
+    }
+    if year_mod.exists() {
+        update.push((year_mod, days));
+    } else {
+        create.insert(0, (year_mod, days));
+    }
+    if let Some((path, _)) = create.iter().find(|(path, _)| path.exists()) {
+        return Err(Error::runtime(format!("{} already exists", path.display())));
+    }
+
+    fs::create_dir_all(dir.join("input"))
+        .map_err(|err| Error::runtime(format!("Failed to create {}: {}", dir.display(), err)))?;
+    // New files go first so that nothing is registered before it exists.
+    for (path, contents) in create.iter().chain(&update) {
+        write(path, contents)?;
+    }
+    Ok(create.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_year() {
+        let lib = "use runner::Day;\n\npub mod runner;\npub mod y19;\npub mod y21;\n\npub static YEARS: &[Year] = &years!(y19, y21);\n";
+        assert_eq!(
+            register(lib, "years", "y20"),
+            Ok("use runner::Day;\n\npub mod runner;\npub mod y19;\npub mod y20;\npub mod y21;\n\npub static YEARS: &[Year] = &years!(y19, y20, y21);\n".into())
+        );
+        assert_eq!(
+            register(lib, "years", "y22"),
+            Ok("use runner::Day;\n\npub mod runner;\npub mod y19;\npub mod y21;\npub mod y22;\n\npub static YEARS: &[Year] = &years!(y19, y21, y22);\n".into())
+        );
+        assert!(register(lib, "years", "y19").is_err());
+    }
+
+    #[test]
+    fn test_new() {
+        let src = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let lib = "use runner::Day;\n\npub mod y19;\n\npub static YEARS: &[Year] = &years!(y19);\n";
+        fs::create_dir_all(src.join("y22")).unwrap();
+        fs::write(src.join("lib.rs"), lib).unwrap();
+        fs::write(src.join("y22").join("d05.rs"), "").unwrap();
+
+        assert!(new(&src, 22, None)
+            .unwrap_err()
+            .to_string()
+            .ends_with("d05.rs already exists"));
+        assert_eq!(fs::read_to_string(src.join("lib.rs")).unwrap(), lib);
+        assert!(!src.join("y22").join("mod.rs").exists());
+
+        let created = new(&src, 22, Some(1)).unwrap();
+        assert_eq!(
+            created,
+            ["mod.rs", "answers.txt", "d01.rs", "input/d01.txt"]
+                .map(|path| src.join("y22").join(path))
+        );
+        assert!(fs::read_to_string(src.join("lib.rs"))
+            .unwrap()
+            .contains("years!(y19, y22)"));
+        let days = fs::read_to_string(src.join("y22").join("mod.rs")).unwrap();
+        assert!(new(&src, 22, Some(1)).is_err());
+        assert_eq!(
+            fs::read_to_string(src.join("y22").join("mod.rs")).unwrap(),
+            days
+        );
+        fs::remove_dir_all(&src).unwrap();
+    }
+
+    #[test]
+    fn test_register_day() {
+        let days = year_template(22);
+        let days = register(&days, "days", "d02").unwrap();
+        assert_eq!(
+            days,
+            "use crate::Day;\n\npub mod d02;\n\npub const YEAR: usize = 22;\n\npub const ANSWERS: &str = include_str!(\"answers.txt\");\n\npub static DAYS: &[Day] = &days!(d02);\n"
+        );
+        let days = register(&days, "days", "d01").unwrap();
+        assert!(days.contains("pub mod d01;\npub mod d02;\n"));
+        assert!(days.contains("&days!(d01, d02);"));
+
+        let days = (3..=25).fold(days, |days, day| {
+            register(&days, "days", &format!("d{:02}", day)).unwrap()
+        });
+        assert!(days.contains(
+            "&days!(\n    d01, d02, d03, d04, d05, d06, d07, d08, d09, d10, d11, d12, d13, d14, d15, d16, d17, d18, d19,\n    d20, d21, d22, d23, d24, d25\n);"
+        ));
+    }
+}
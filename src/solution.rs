@@ -0,0 +1,185 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::error::Error;
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Self; 2] = [Self::One, Self::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl std::str::FromStr for Part {
+    type Err = String;
+
+    fn from_str(part: &str) -> Result<Self, Self::Err> {
+        match part {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("Invalid part: {}", part)),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Answer {
+    Num(i128),
+    Text(String),
+    Image(Vec<String>),
+}
+
+impl Answer {
+    pub fn image(rows: &str) -> Self {
+        Self::Image(rows.lines().map(String::from).collect())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Num(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+            Self::Image(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            #[allow(clippy::cast_lossless, clippy::cast_possible_wrap)]
+            fn from(n: $t) -> Self {
+                Self::Num(n as i128)
+            }
+        })*
+    }
+}
+
+answer_from_int!(u16, u32, u64, usize, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.into())
+    }
+}
+
+// A worked example from the puzzle text and the answers it should produce.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub const fn expected(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+
+    // The parts this example has expected answers for.
+    pub fn parts(&self) -> Vec<Part> {
+        Part::BOTH
+            .iter()
+            .copied()
+            .filter(|part| self.expected(*part).is_some())
+            .collect()
+    }
+}
+
+pub trait Solution {
+    const YEAR: usize;
+    const DAY: usize;
+    const TITLE: &'static str;
+    const EXAMPLES: &'static [Example] = &[];
+    // The parts that are solved, as opposed to stubbed out.
+    const PARTS: &'static [Part] = &Part::BOTH;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Result<Answer, Error>;
+    fn part2(input: &Self::Input) -> Result<Answer, Error>;
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Outcome {
+    pub parse: Duration,
+    pub answers: Vec<(Part, Answer, Duration)>,
+}
+
+impl Outcome {
+    pub fn solve(&self) -> Duration {
+        self.answers.iter().map(|(_, _, time)| *time).sum()
+    }
+}
+
+pub trait Run: Sync {
+    fn year(&self) -> usize;
+    fn day(&self) -> usize;
+    fn title(&self) -> &'static str;
+    fn examples(&self) -> &'static [Example];
+    fn parts(&self) -> &'static [Part];
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Outcome, Error>;
+}
+
+impl<S: Solution + Sync> Run for S {
+    fn year(&self) -> usize {
+        S::YEAR
+    }
+
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Outcome, Error> {
+        let start = Instant::now();
+        let input = S::parse(input)?;
+        let parse = start.elapsed();
+        let answers = parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => S::part1(&input),
+                    Part::Two => S::part2(&input),
+                }?;
+                Ok((*part, answer, start.elapsed()))
+            })
+            .collect::<Result<_, Error>>()?;
+        Ok(Outcome { parse, answers })
+    }
+}
@@ -0,0 +1,60 @@
+// Which side of its column a cell's text sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+// Lay out `rows` under `header` in columns two spaces apart, each as wide as
+// its widest cell and aligned as in `align`.
+pub fn render<const N: usize>(
+    header: [&str; N],
+    align: [Align; N],
+    rows: &[[String; N]],
+) -> String {
+    let header = header.map(String::from);
+    let widths = rows.iter().fold(
+        header.clone().map(|col| col.chars().count()),
+        |mut widths, row| {
+            for (width, col) in widths.iter_mut().zip(row) {
+                *width = (*width).max(col.chars().count());
+            }
+            widths
+        },
+    );
+    let mut out = String::new();
+    for row in std::iter::once(&header).chain(rows) {
+        let cols = row
+            .iter()
+            .zip(widths.iter().zip(align))
+            .map(|(col, (&width, align))| match align {
+                Align::Left => format!("{:<w$}", col, w = width),
+                Align::Right => format!("{:>w$}", col, w = width),
+            })
+            .collect::<Vec<_>>();
+        out += cols.join("  ").trim_end();
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let rows = [
+            ["1".into(), "one".into(), "x".into()],
+            ["22".into(), "two".into(), "".into()],
+        ];
+        assert_eq!(
+            render(
+                ["Day", "Name", "Note"],
+                [Align::Right, Align::Left, Align::Left],
+                &rows
+            ),
+            "Day  Name  Note\n  1  one   x\n 22  two\n"
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use crate::error::{column, Error};
+use crate::runner::{self, Report, Status::*};
+use crate::solution::{Answer, Example, Part};
+use crate::YEARS;
+
+pub const EXIT_MISMATCH: u8 = 5;
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Expected {
+    pub day: usize,
+    pub part: Part,
+    pub answer: String,
+}
+
+pub fn parse(answers: &str) -> Result<Vec<Expected>, Error> {
+    let mut expected: Vec<Expected> = vec![];
+    for (lineno, line) in answers.lines().enumerate() {
+        let err =
+            |field: &str| Error::parse(lineno + 1, column(line, field), line, "Invalid answer");
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(row) = line.strip_prefix('|') {
+            let last = expected.last_mut().ok_or_else(|| err(line))?;
+            if !last.answer.is_empty() {
+                last.answer.push('\n');
+            }
+            last.answer.push_str(row);
+        } else {
+            let mut fields = line.splitn(3, ' ');
+            let day = fields.next().unwrap_or(line);
+            let day = day.parse().map_err(|_| err(day))?;
+            let part = fields.next().unwrap_or(&line[line.len()..]);
+            let part = part.parse().map_err(|_| err(part))?;
+            let answer = fields.next().unwrap_or("").into();
+            expected.push(Expected { day, part, answer });
+        }
+    }
+    Ok(expected)
+}
+
+pub fn matches(answer: &Answer, expected: &str) -> bool {
+    let answer = answer.to_string();
+    answer
+        .lines()
+        .map(str::trim_end)
+        .eq(expected.lines().map(str::trim_end))
+}
+
+pub fn expected_for(year: usize, day: usize) -> Result<Vec<Expected>, Error> {
+    let (_, _, answers) = YEARS
+        .iter()
+        .find(|(y, _, _)| *y == year)
+        .ok_or_else(|| Error::runtime("Year out of range"))?;
+    Ok(parse(answers)?
+        .into_iter()
+        .filter(|expected| expected.day == day)
+        .collect())
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Verdict {
+    Correct,
+    Wrong(Vec<String>),
+    Unrecorded,
+    Skipped(String),
+}
+
+impl Verdict {
+    pub const fn exit_code(&self) -> u8 {
+        match self {
+            Self::Wrong(_) => EXIT_MISMATCH,
+            Self::Correct | Self::Unrecorded | Self::Skipped(_) => 0,
+        }
+    }
+}
+
+pub fn verify_day(year: usize, day: usize) -> Result<Verdict, Error> {
+    let expected = expected_for(year, day)?;
+    let parts = expected
+        .iter()
+        .map(|expected| expected.part)
+        .collect::<Vec<_>>();
+    if parts.is_empty() {
+        let report = runner::run_day(year, day, None, &Part::BOTH)?;
+        return Ok(match report.status {
+            Solved(_) => Verdict::Unrecorded,
+            Unimplemented | NoInput => Verdict::Skipped(report.status()),
+            Failed(_) | Panicked(_) | TimedOut(_) => Verdict::Wrong(vec![report.status()]),
+        });
+    }
+
+    let report = runner::run_day(year, day, None, &parts)?;
+    Ok(judge(
+        &report,
+        expected
+            .iter()
+            .map(|expected| (expected.part, expected.answer.as_str())),
+    ))
+}
+
+fn judge<'a>(report: &Report, expected: impl Iterator<Item = (Part, &'a str)>) -> Verdict {
+    if !matches!(report.status, Solved(_)) {
+        return Verdict::Wrong(vec![report.status()]);
+    }
+    let wrong = expected
+        .filter_map(|(part, expected)| match report.answer(part) {
+            Some(answer) if matches(answer, expected) => None,
+            Some(answer) => Some(format!(
+                "part {}: expected {:?}, got {:?}",
+                part,
+                expected,
+                answer.to_string()
+            )),
+            None => Some(format!(
+                "part {}: expected {:?}, not implemented",
+                part, expected
+            )),
+        })
+        .collect::<Vec<_>>();
+    if wrong.is_empty() {
+        Verdict::Correct
+    } else {
+        Verdict::Wrong(wrong)
+    }
+}
+
+// Examples are numbered from 1, in the order they appear in the puzzle.
+pub fn find_example(year: usize, day: usize, n: usize) -> Result<&'static Example, Error> {
+    let (run, _) = runner::find_day(year, day)?;
+    n.checked_sub(1)
+        .and_then(|idx| run.examples().get(idx))
+        .ok_or_else(|| {
+            Error::runtime(format!(
+                "Example {} not found ({} has {})",
+                n,
+                run.title(),
+                run.examples().len()
+            ))
+        })
+}
+
+pub fn verify_example(year: usize, day: usize, n: usize) -> Result<Verdict, Error> {
+    let example = find_example(year, day, n)?;
+    let parts = example.parts();
+    let report = runner::run_day(year, day, Some(example.input), &parts)?;
+    Ok(judge(
+        &report,
+        parts
+            .into_iter()
+            .filter_map(|part| Some((part, example.expected(part)?))),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = "# DAY PART ANSWER\n1 1 42\n1 2 abc\n8 2\n|# #\n| # \n";
+        assert_eq!(
+            parse(answers),
+            Ok(vec![
+                Expected {
+                    day: 1,
+                    part: Part::One,
+                    answer: "42".into()
+                },
+                Expected {
+                    day: 1,
+                    part: Part::Two,
+                    answer: "abc".into()
+                },
+                Expected {
+                    day: 8,
+                    part: Part::Two,
+                    answer: "# #\n # ".into()
+                },
+            ])
+        );
+        assert_eq!(
+            parse("1 1 42\n1 3 42"),
+            Err(Error::parse(2, 3, "1 3 42", "Invalid answer"))
+        );
+        assert_eq!(parse("|#"), Err(Error::parse(1, 1, "|#", "Invalid answer")));
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(matches(&Answer::from(42), "42"));
+        assert!(!matches(&Answer::from(42), "43"));
+        assert!(matches(&Answer::image("# #  \n #\n"), "# #\n #"));
+    }
+}
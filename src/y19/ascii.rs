@@ -0,0 +1,159 @@
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::io;
+use std::iter;
+
+use super::intcode::{Intcode, Runtime};
+use crate::error::Error;
+
+// What a program printed before it stopped.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Reply {
+    pub text: String,
+    // The last value printed outside the ASCII range, usually the answer.
+    pub answer: Option<i64>,
+    pub halted: bool,
+}
+
+impl Reply {
+    // The text as a grid of characters, one row per non-empty line, as the
+    // camera views of d17 are printed.
+    pub fn grid(&self) -> Vec<Vec<char>> {
+        self.text
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().collect())
+            .collect()
+    }
+}
+
+// Runs an Intcode program that reads and writes lines of ASCII text.
+#[derive(Debug, Clone)]
+pub struct Ascii {
+    rt: Runtime<VecDeque<i64>, io::Sink>,
+    budget: Option<u64>,
+}
+
+impl Ascii {
+    pub fn new(prog: &Intcode) -> Self {
+        Self {
+            rt: prog.exec(),
+            budget: None,
+        }
+    }
+
+    // Fail any read that takes more than `steps` instructions, for programs
+    // that can get stuck in a loop.
+    #[must_use]
+    pub const fn with_budget(mut self, steps: u64) -> Self {
+        self.budget = Some(steps);
+        self
+    }
+
+    pub const fn runtime(&mut self) -> &mut Runtime<VecDeque<i64>, io::Sink> {
+        &mut self.rt
+    }
+
+    // Queue `line` followed by a newline.
+    pub fn send(&mut self, line: &str) {
+        let codes = line.bytes().chain(iter::once(b'\n')).map(i64::from);
+        self.rt.read_next(&codes.collect::<Vec<_>>());
+    }
+
+    // Run until the program waits for input or halts.
+    pub fn read(&mut self) -> Result<Reply, Error> {
+        self.read_until("")
+    }
+
+    // Run until the output ends with `prompt`, the program waits for input
+    // or it halts.
+    pub fn read_until(&mut self, prompt: &str) -> Result<Reply, Error> {
+        let mut reply = Reply::default();
+        let mut steps = 0;
+        loop {
+            if self.budget == Some(steps) {
+                return Err(Error::vm(
+                    self.rt.ptr(),
+                    format!("Still running after {} steps", steps),
+                ));
+            }
+            steps += 1;
+            if !prompt.is_empty() && reply.text.ends_with(prompt) {
+                return Ok(reply);
+            }
+            if self.rt.awaiting_input() {
+                return Ok(reply);
+            }
+            match self.rt.next() {
+                None => {
+                    reply.halted = true;
+                    return Ok(reply);
+                }
+                Some(Err(err)) => return Err(err),
+                Some(Ok(Some(val))) => match u8::try_from(val).ok().filter(u8::is_ascii) {
+                    Some(c) => reply.text.push(char::from(c)),
+                    None => reply.answer = Some(val),
+                },
+                Some(Ok(None)) => {}
+            }
+        }
+    }
+
+    // Send `line` and read the reply to it.
+    pub fn command(&mut self, line: &str) -> Result<Reply, Error> {
+        self.send(line);
+        self.read()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii() {
+        let src = "
+                    out #72
+                    out #105
+                    out #10
+                    out #62
+                    out #32
+            loop:   in [c]
+                    eq [c], #10, [t]
+                    jnz [t], #done
+                    out [c]
+                    jz #0, #loop
+            done:   out #10
+                    out #1000
+                    hlt
+            c:      .data 0
+            t:      .data 0
+        ";
+        let mut prog = Ascii::new(&Intcode::assemble(src).unwrap());
+        let reply = prog.read_until("> ").unwrap();
+        assert_eq!(reply.text, "Hi\n> ");
+        assert!(!reply.halted);
+        assert_eq!(prog.read(), Ok(Reply::default()));
+        assert_eq!(
+            prog.clone().with_budget(10).command("abcdefgh"),
+            Err(Error::vm(10, "Still running after 10 steps"))
+        );
+        assert_eq!(
+            prog.command("abc"),
+            Ok(Reply {
+                text: "abc\n".into(),
+                answer: Some(1000),
+                halted: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let reply = Reply {
+            text: "#.\n.#\n\n".into(),
+            ..Reply::default()
+        };
+        assert_eq!(reply.grid(), [['#', '.'], ['.', '#']]);
+    }
+}
@@ -1,3 +1,6 @@
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 const fn calc_fuel(mass: u64) -> u64 {
     (mass / 3).saturating_sub(2)
 }
@@ -20,18 +23,56 @@ fn part2(masses: &[u64]) -> u64 {
     masses.iter().copied().map(calc_fuel_all).sum()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d01.txt");
-    let masses = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|_| "Invalid input")?;
-    let out1 = part1(&masses);
-    let out2 = part2(&masses);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d01.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "12",
+        part1: Some("2"),
+        part2: None,
+    },
+    Example {
+        input: "14",
+        part1: Some("2"),
+        part2: Some("2"),
+    },
+    Example {
+        input: "1969",
+        part1: Some("654"),
+        part2: Some("966"),
+    },
+    Example {
+        input: "100756",
+        part1: Some("33583"),
+        part2: Some("50346"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "The Tyranny of the Rocket Equation";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input)
+    }
+
+    fn part1(masses: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(masses).into())
+    }
+
+    fn part2(masses: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(masses).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
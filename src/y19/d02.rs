@@ -1,12 +1,14 @@
 use super::intcode::Intcode;
+use crate::error::Error;
+use crate::solution::{Answer, Run, Solution};
 
-fn part1(prog: &Intcode) -> Result<i64, String> {
+fn part1(prog: &Intcode) -> Result<i64, Error> {
     let mut exec = prog.exec();
     exec.run_with(&[(1, 12), (2, 2)])?;
     Ok(exec[0])
 }
 
-fn part2(prog: &Intcode) -> Result<i64, String> {
+fn part2(prog: &Intcode) -> Result<i64, Error> {
     for noun in 0..99 {
         for verb in 0..99 {
             let mut exec = prog.exec();
@@ -15,13 +17,31 @@ fn part2(prog: &Intcode) -> Result<i64, String> {
             }
         }
     }
-    Err("No solution found".into())
+    Err(Error::no_solution("No solution found"))
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d02.txt");
-    let prog = input.parse()?;
-    let out1 = part1(&prog)?;
-    let out2 = part2(&prog)?;
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d02.txt");
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 2;
+    const TITLE: &'static str = "1202 Program Alarm";
+
+    type Input = Intcode;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(prog: &Self::Input) -> Result<Answer, Error> {
+        part1(prog).map(Answer::from)
+    }
+
+    fn part2(prog: &Self::Input) -> Result<Answer, Error> {
+        part2(prog).map(Answer::from)
+    }
 }
+
+pub const SOLVER: &dyn Run = &Solver;
@@ -1,6 +1,9 @@
 use std::collections::HashSet;
 use std::str::FromStr;
 
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 type Point = (i64, i64);
 
 #[allow(clippy::cast_sign_loss)]
@@ -135,17 +138,65 @@ fn part2(wire1: &Wire, wire2: &Wire) -> u64 {
         .unwrap_or(0)
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d03.txt");
-    let wires = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = part1(&wires[0], &wires[1]);
-    let out2 = part2(&wires[0], &wires[1]);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d03.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "R8,U5,L5,D3\n\
+                U7,R6,D4,L4",
+        part1: Some("6"),
+        part2: Some("30"),
+    },
+    Example {
+        input: "R75,D30,R83,U83,L12,D49,R71,U7,L72\n\
+                U62,R66,U55,R34,D71,R55,D58,R83",
+        part1: Some("159"),
+        part2: Some("610"),
+    },
+    Example {
+        input: "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\n\
+                U98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
+        part1: Some("135"),
+        part2: Some("410"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 3;
+    const TITLE: &'static str = "Crossed Wires";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Wire>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let wires = parse_lines(input)?;
+        if wires.len() == 2 {
+            Ok(wires)
+        } else {
+            let line = input.lines().nth(2).unwrap_or_default();
+            Err(Error::parse(
+                wires.len().min(2) + 1,
+                1,
+                line,
+                "Expected two wires",
+            ))
+        }
+    }
+
+    fn part1(wires: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(&wires[0], &wires[1]).into())
+    }
+
+    fn part2(wires: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(&wires[0], &wires[1]).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,159 +233,21 @@ mod tests {
 
     #[test]
     fn test01() {
-        assert_eq!(
-            part1(
-                &Wire {
-                    path: vec![(Right, 8), (Up, 5), (Left, 5), (Down, 3)]
-                },
-                &Wire {
-                    path: vec![(Up, 7), (Right, 6), (Down, 4), (Left, 4)]
-                }
-            ),
-            6
-        );
-        assert_eq!(
-            part1(
-                &Wire {
-                    path: vec![
-                        (Right, 75),
-                        (Down, 30),
-                        (Right, 83),
-                        (Up, 83),
-                        (Left, 12),
-                        (Down, 49),
-                        (Right, 71),
-                        (Up, 7),
-                        (Left, 72)
-                    ]
-                },
-                &Wire {
-                    path: vec![
-                        (Up, 62),
-                        (Right, 66),
-                        (Up, 55),
-                        (Right, 34),
-                        (Down, 71),
-                        (Right, 55),
-                        (Down, 58),
-                        (Right, 83)
-                    ]
-                }
-            ),
-            159
-        );
-        assert_eq!(
-            part1(
-                &Wire {
-                    path: vec![
-                        (Right, 98),
-                        (Up, 47),
-                        (Right, 26),
-                        (Down, 63),
-                        (Right, 33),
-                        (Up, 87),
-                        (Left, 62),
-                        (Down, 20),
-                        (Right, 33),
-                        (Up, 53),
-                        (Right, 51)
-                    ]
-                },
-                &Wire {
-                    path: vec![
-                        (Up, 98),
-                        (Right, 91),
-                        (Down, 20),
-                        (Right, 16),
-                        (Down, 67),
-                        (Right, 40),
-                        (Up, 7),
-                        (Right, 15),
-                        (Up, 6),
-                        (Right, 7)
-                    ]
-                }
-            ),
-            135
-        );
+        let wires = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(part1(&wires[0], &wires[1]), 6);
+        let wires = Solver::parse(EXAMPLES[1].input).unwrap();
+        assert_eq!(part1(&wires[0], &wires[1]), 159);
+        let wires = Solver::parse(EXAMPLES[2].input).unwrap();
+        assert_eq!(part1(&wires[0], &wires[1]), 135);
     }
 
     #[test]
     fn test02() {
-        assert_eq!(
-            part2(
-                &Wire {
-                    path: vec![(Right, 8), (Up, 5), (Left, 5), (Down, 3)]
-                },
-                &Wire {
-                    path: vec![(Up, 7), (Right, 6), (Down, 4), (Left, 4)]
-                }
-            ),
-            30
-        );
-        assert_eq!(
-            part2(
-                &Wire {
-                    path: vec![
-                        (Right, 75),
-                        (Down, 30),
-                        (Right, 83),
-                        (Up, 83),
-                        (Left, 12),
-                        (Down, 49),
-                        (Right, 71),
-                        (Up, 7),
-                        (Left, 72)
-                    ]
-                },
-                &Wire {
-                    path: vec![
-                        (Up, 62),
-                        (Right, 66),
-                        (Up, 55),
-                        (Right, 34),
-                        (Down, 71),
-                        (Right, 55),
-                        (Down, 58),
-                        (Right, 83)
-                    ]
-                }
-            ),
-            610
-        );
-        assert_eq!(
-            part2(
-                &Wire {
-                    path: vec![
-                        (Right, 98),
-                        (Up, 47),
-                        (Right, 26),
-                        (Down, 63),
-                        (Right, 33),
-                        (Up, 87),
-                        (Left, 62),
-                        (Down, 20),
-                        (Right, 33),
-                        (Up, 53),
-                        (Right, 51)
-                    ]
-                },
-                &Wire {
-                    path: vec![
-                        (Up, 98),
-                        (Right, 91),
-                        (Down, 20),
-                        (Right, 16),
-                        (Down, 67),
-                        (Right, 40),
-                        (Up, 7),
-                        (Right, 15),
-                        (Up, 6),
-                        (Right, 7)
-                    ]
-                }
-            ),
-            410
-        );
+        let wires = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(part2(&wires[0], &wires[1]), 30);
+        let wires = Solver::parse(EXAMPLES[1].input).unwrap();
+        assert_eq!(part2(&wires[0], &wires[1]), 610);
+        let wires = Solver::parse(EXAMPLES[2].input).unwrap();
+        assert_eq!(part2(&wires[0], &wires[1]), 410);
     }
 }
@@ -1,3 +1,6 @@
+use crate::error::{parse_field, Error};
+use crate::solution::{Answer, Run, Solution};
+
 fn digits(x: u64) -> Vec<u32> {
     x.to_string()
         .chars()
@@ -54,19 +57,36 @@ fn part2(min: u64, max: u64) -> usize {
     count_valid(min, max, valid2)
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d04.txt");
-    let minmax = input
-        .trim()
-        .split('-')
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|_| "Invalid input")?;
-    let out1 = part1(minmax[0], minmax[1]);
-    let out2 = part2(minmax[0], minmax[1]);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d04.txt");
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 4;
+    const TITLE: &'static str = "Secure Container";
+
+    type Input = (u64, u64);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let line = input.trim();
+        let (min, max) = line
+            .split_once('-')
+            .ok_or_else(|| Error::parse(1, 1, line, "Expected a range"))?;
+        Ok((parse_field(1, line, min)?, parse_field(1, line, max)?))
+    }
+
+    fn part1((min, max): &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(*min, *max).into())
+    }
+
+    fn part2((min, max): &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(*min, *max).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
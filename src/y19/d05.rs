@@ -1,27 +1,49 @@
 use super::intcode::Intcode;
+use crate::error::Error;
+use crate::solution::{Answer, Run, Solution};
 
-fn part1(prog: &Intcode) -> Result<i64, String> {
+fn part1(prog: &Intcode) -> Result<i64, Error> {
     let mut exec = prog.exec().read_vec(&[1]).write_to(vec![]);
     let out = exec.run()?;
     if out[..out.len().saturating_sub(1)].iter().all(|x| *x == 0) {
-        out.last().copied().ok_or_else(|| "No output".into())
+        out.last()
+            .copied()
+            .ok_or_else(|| Error::runtime("No output"))
     } else {
-        Err("Failed diagnostic".into())
+        Err(Error::runtime("Failed diagnostic"))
     }
 }
 
-fn part2(prog: &Intcode) -> Result<i64, String> {
+fn part2(prog: &Intcode) -> Result<i64, Error> {
     prog.exec()
         .read_vec(&[5])
         .write_to(vec![])
         .run_to_out()
-        .and_then(|res| res.ok_or_else(|| "No return value".into()))
+        .and_then(|res| res.ok_or_else(|| Error::runtime("No return value")))
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d05.txt");
-    let prog = input.parse()?;
-    let out1 = part1(&prog)?;
-    let out2 = part2(&prog)?;
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d05.txt");
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 5;
+    const TITLE: &'static str = "Sunny with a Chance of Asteroids";
+
+    type Input = Intcode;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(prog: &Self::Input) -> Result<Answer, Error> {
+        part1(prog).map(Answer::from)
+    }
+
+    fn part2(prog: &Self::Input) -> Result<Answer, Error> {
+        part2(prog).map(Answer::from)
+    }
 }
+
+pub const SOLVER: &dyn Run = &Solver;
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 use std::str::FromStr;
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(PartialEq, Eq, Debug)]
 struct Orbits(HashMap<String, String>);
 
@@ -12,18 +15,19 @@ struct Parents<'a> {
 }
 
 impl FromStr for Orbits {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(orbits: &str) -> Result<Self, Self::Err> {
         Ok(Self(
             orbits
                 .lines()
-                .map(|orbit| {
+                .enumerate()
+                .map(|(idx, orbit)| {
                     let objs = orbit.split(')').collect::<Vec<_>>();
                     if objs.len() == 2 {
                         Ok((objs[1].into(), objs[0].into()))
                     } else {
-                        Err("Invalid orbit".into())
+                        Err(Error::parse(idx + 1, 1, orbit, "Invalid orbit"))
                     }
                 })
                 .collect::<Result<_, Self::Err>>()?,
@@ -77,33 +81,75 @@ fn part2(orbits: &Orbits) -> u64 {
     orbits.distance("YOU", "SAN") - 2
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d06.txt");
-    let orbits = input.parse()?;
-    let out1 = part1(&orbits);
-    let out2 = part2(&orbits);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d06.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "COM)B\n\
+                B)C\n\
+                C)D\n\
+                D)E\n\
+                E)F\n\
+                B)G\n\
+                G)H\n\
+                D)I\n\
+                E)J\n\
+                J)K\n\
+                K)L",
+        part1: Some("42"),
+        part2: None,
+    },
+    Example {
+        input: "COM)B\n\
+                B)C\n\
+                C)D\n\
+                D)E\n\
+                E)F\n\
+                B)G\n\
+                G)H\n\
+                D)I\n\
+                E)J\n\
+                J)K\n\
+                K)L\n\
+                K)YOU\n\
+                I)SAN",
+        part1: None,
+        part2: Some("4"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 6;
+    const TITLE: &'static str = "Universal Orbit Map";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Orbits;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(orbits: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(orbits).into())
+    }
+
+    fn part2(orbits: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(orbits).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let orbits = "COM)B\n\
-                      B)C\n\
-                      C)D\n\
-                      D)E\n\
-                      E)F\n\
-                      B)G\n\
-                      G)H\n\
-                      D)I\n\
-                      E)J\n\
-                      J)K\n\
-                      K)L"
-        .parse::<Orbits>()
-        .unwrap();
+        let orbits = EXAMPLES[0].input.parse::<Orbits>().unwrap();
         assert_eq!(orbits.depth("D"), 3);
         assert_eq!(orbits.depth("L"), 7);
         assert_eq!(part1(&orbits), 42);
@@ -111,21 +157,7 @@ mod tests {
 
     #[test]
     fn test02() {
-        let orbits = "COM)B\n\
-                      B)C\n\
-                      C)D\n\
-                      D)E\n\
-                      E)F\n\
-                      B)G\n\
-                      G)H\n\
-                      D)I\n\
-                      E)J\n\
-                      J)K\n\
-                      K)L\n\
-                      K)YOU\n\
-                      I)SAN"
-            .parse::<Orbits>()
-            .unwrap();
+        let orbits = EXAMPLES[1].input.parse::<Orbits>().unwrap();
         assert_eq!(part2(&orbits), 4);
     }
 }
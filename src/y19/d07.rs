@@ -1,18 +1,20 @@
 use itertools::Itertools;
 
 use super::intcode::Intcode;
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
 
-fn run_amp(prog: &Intcode, phases: &[i64]) -> Result<i64, String> {
+fn run_amp(prog: &Intcode, phases: &[i64]) -> Result<i64, Error> {
     phases.iter().try_fold(0, |input, phase| {
         prog.exec()
             .read_vec(&[*phase, input])
             .write_to(vec![])
             .run_to_out()
-            .and_then(|res| res.ok_or_else(|| "No return value".into()))
+            .and_then(|res| res.ok_or_else(|| Error::runtime("No return value")))
     })
 }
 
-fn run_amp_feedback(prog: &Intcode, phases: &[i64]) -> Result<i64, String> {
+fn run_amp_feedback(prog: &Intcode, phases: &[i64]) -> Result<i64, Error> {
     let mut amps = phases
         .iter()
         .map(|phase| prog.exec().read_vec(&[*phase]).write_to(vec![]))
@@ -30,7 +32,7 @@ fn run_amp_feedback(prog: &Intcode, phases: &[i64]) -> Result<i64, String> {
     }
 }
 
-fn part1(prog: &Intcode) -> Result<i64, String> {
+fn part1(prog: &Intcode) -> Result<i64, Error> {
     (0..=4)
         .permutations(5)
         .map(|phases| run_amp(prog, &phases))
@@ -38,7 +40,7 @@ fn part1(prog: &Intcode) -> Result<i64, String> {
         .map(|outs| outs.into_iter().max().unwrap())
 }
 
-fn part2(prog: &Intcode) -> Result<i64, String> {
+fn part2(prog: &Intcode) -> Result<i64, Error> {
     (5..=9)
         .permutations(5)
         .map(|phases| run_amp_feedback(prog, &phases))
@@ -46,48 +48,85 @@ fn part2(prog: &Intcode) -> Result<i64, String> {
         .map(|outs| outs.into_iter().max().unwrap())
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d07.txt");
-    let prog = input.parse()?;
-    let out1 = part1(&prog)?;
-    let out2 = part2(&prog)?;
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d07.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0",
+        part1: Some("43210"),
+        part2: None,
+    },
+    Example {
+        input: "3,23,3,24,1002,24,10,24,1002,23,-1,23,\
+                101,5,23,23,1,24,23,23,4,23,99,0,0",
+        part1: Some("54321"),
+        part2: None,
+    },
+    Example {
+        input: "3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,\
+                1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0",
+        part1: Some("65210"),
+        part2: None,
+    },
+    Example {
+        input: "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,\
+                27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5",
+        part1: None,
+        part2: Some("139629729"),
+    },
+    Example {
+        input: "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,\
+                -5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,\
+                53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10",
+        part1: None,
+        part2: Some("18216"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 7;
+    const TITLE: &'static str = "Amplification Circuit";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Intcode;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(prog: &Self::Input) -> Result<Answer, Error> {
+        part1(prog).map(Answer::from)
+    }
+
+    fn part2(prog: &Self::Input) -> Result<Answer, Error> {
+        part2(prog).map(Answer::from)
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let p = "3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0"
-            .parse()
-            .unwrap();
+        let p = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&p), Ok(43210));
-        let p = "3,23,3,24,1002,24,10,24,1002,23,-1,23,\
-                 101,5,23,23,1,24,23,23,4,23,99,0,0"
-            .parse()
-            .unwrap();
+        let p = Solver::parse(EXAMPLES[1].input).unwrap();
         assert_eq!(part1(&p), Ok(54321));
-        let p = "3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,\
-                 1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0"
-            .parse()
-            .unwrap();
+        let p = Solver::parse(EXAMPLES[2].input).unwrap();
         assert_eq!(part1(&p), Ok(65210));
     }
 
     #[test]
     fn test02() {
-        let p = "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,\
-                 27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5"
-            .parse()
-            .unwrap();
+        let p = Solver::parse(EXAMPLES[3].input).unwrap();
         assert_eq!(part2(&p), Ok(139_629_729));
-        let p = "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,\
-                 -5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,\
-                 53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10"
-            .parse()
-            .unwrap();
+        let p = Solver::parse(EXAMPLES[4].input).unwrap();
         assert_eq!(part2(&p), Ok(18216));
     }
 }
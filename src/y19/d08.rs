@@ -1,6 +1,9 @@
 use std::fmt;
 use std::ops::BitOr;
 
+use crate::error::Error;
+use crate::solution::{Answer, Run, Solution};
+
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 enum Pixel {
     Black,
@@ -10,12 +13,12 @@ enum Pixel {
 use Pixel::*;
 
 impl Pixel {
-    fn new(pix: u32) -> Self {
+    const fn new(pix: char) -> Option<Self> {
         match pix {
-            0 => Black,
-            1 => White,
-            2 => Transparent,
-            _ => unreachable!(),
+            '0' => Some(Black),
+            '1' => Some(White),
+            '2' => Some(Transparent),
+            _ => None,
         }
     }
 }
@@ -64,20 +67,28 @@ struct Image {
 }
 
 impl Layers {
-    fn new(width: usize, height: usize, pixels: &str) -> Self {
-        Self {
+    fn new(width: usize, height: usize, pixels: &str) -> Result<Self, Error> {
+        let pixels = pixels.trim();
+        if !pixels.len().is_multiple_of(width * height) {
+            let msg = format!("Expected layers of {} pixels", width * height);
+            return Err(Error::parse(1, pixels.len() + 1, pixels, msg));
+        }
+        Ok(Self {
             width,
             height,
             depth: pixels.len() / (width * height),
             layers: pixels
-                .trim()
                 .chars()
-                .map(|c| Pixel::new(c.to_digit(10).unwrap()))
-                .collect::<Vec<_>>()
+                .enumerate()
+                .map(|(col, c)| {
+                    let msg = || format!("Invalid pixel {:?}", c);
+                    Pixel::new(c).ok_or_else(|| Error::parse(1, col + 1, pixels, msg()))
+                })
+                .collect::<Result<Vec<_>, _>>()?
                 .chunks(width * height)
                 .map(|layer| Layer(layer.chunks(width).map(<[_]>::to_vec).collect()))
                 .collect(),
-        }
+        })
     }
 
     fn count_pixel(&self, layer: usize, pix: Pixel) -> usize {
@@ -132,15 +143,32 @@ fn part2(layers: &Layers) -> String {
     format!("{}", layers.decode())
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d08.txt");
-    let img = Layers::new(25, 6, input);
-    let out1 = part1(&img);
-    let out2 = part2(&img);
-    Ok(format!("{}\n{}", out1, out2))
+pub const INPUT: &str = include_str!("input/d08.txt");
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 8;
+    const TITLE: &'static str = "Space Image Format";
+
+    type Input = Layers;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Layers::new(25, 6, input)
+    }
+
+    fn part1(img: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(img).into())
+    }
+
+    fn part2(img: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::image(&part2(img)))
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,7 +176,10 @@ mod tests {
     #[test]
     fn test_decode() {
         assert_eq!(
-            Layers::new(2, 2, "0222112222120000").decode().pixels,
+            Layers::new(2, 2, "0222112222120000")
+                .unwrap()
+                .decode()
+                .pixels,
             Layer(vec![vec![Black, White], vec![White, Black]])
         );
     }
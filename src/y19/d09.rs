@@ -1,25 +1,53 @@
 use super::intcode::Intcode;
+use crate::error::Error;
+use crate::solution::{Answer, Run, Solution};
 
-fn part1(prog: &Intcode) -> Result<i64, String> {
+fn part1(prog: &Intcode) -> Result<i64, Error> {
     prog.exec()
         .read_vec(&[1])
         .write_to(vec![])
         .run()
-        .and_then(|out| out.first().copied().ok_or_else(|| "No return value".into()))
+        .and_then(|out| {
+            out.first()
+                .copied()
+                .ok_or_else(|| Error::runtime("No return value"))
+        })
 }
 
-fn part2(prog: &Intcode) -> Result<i64, String> {
+fn part2(prog: &Intcode) -> Result<i64, Error> {
     prog.exec()
         .read_vec(&[2])
         .write_to(vec![])
         .run()
-        .and_then(|out| out.first().copied().ok_or_else(|| "No return value".into()))
+        .and_then(|out| {
+            out.first()
+                .copied()
+                .ok_or_else(|| Error::runtime("No return value"))
+        })
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d09.txt");
-    let prog = input.parse()?;
-    let out1 = part1(&prog)?;
-    let out2 = part2(&prog)?;
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d09.txt");
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 9;
+    const TITLE: &'static str = "Sensor Boost";
+
+    type Input = Intcode;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(prog: &Self::Input) -> Result<Answer, Error> {
+        part1(prog).map(Answer::from)
+    }
+
+    fn part2(prog: &Self::Input) -> Result<Answer, Error> {
+        part2(prog).map(Answer::from)
+    }
 }
+
+pub const SOLVER: &dyn Run = &Solver;
@@ -2,6 +2,9 @@ use std::collections::HashSet;
 use std::ops::{Add, Sub};
 use std::str::FromStr;
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Part, Run, Solution};
+
 fn in_range(x: isize, y: isize, z: isize) -> bool {
     let min = x.min(z);
     let max = x.max(z);
@@ -61,20 +64,26 @@ impl Sub for Point {
 struct Map(HashSet<Point>);
 
 impl FromStr for Map {
-    type Err = String;
+    type Err = Error;
 
     #[allow(clippy::cast_possible_wrap)]
     fn from_str(map: &str) -> Result<Self, Self::Err> {
-        Ok(Self(
-            map.lines()
-                .enumerate()
-                .flat_map(|(y, line)| {
-                    line.chars().enumerate().filter_map(move |(x, c)| {
-                        (c == '#').then(|| Point::new(x as isize, y as isize))
-                    })
-                })
-                .collect(),
-        ))
+        let mut asteroids = HashSet::new();
+        for (y, line) in map.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        asteroids.insert(Point::new(x as isize, y as isize));
+                    }
+                    '.' => {}
+                    _ => {
+                        let msg = format!("Invalid cell {:?}", c);
+                        return Err(Error::parse(y + 1, x + 1, line, msg));
+                    }
+                }
+            }
+        }
+        Ok(Self(asteroids))
     }
 }
 
@@ -162,27 +171,120 @@ fn part2(map: &Map, p: Point) -> isize {
         .unwrap()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d10.txt");
-    let map = input.parse()?;
-    let (p, out1) = part1(&map);
-    let out2 = part2(&map, p);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d10.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: ".#..#\n\
+                .....\n\
+                #####\n\
+                ....#\n\
+                ...##",
+        part1: Some("8"),
+        part2: None,
+    },
+    Example {
+        input: "......#.#.\n\
+                #..#.#....\n\
+                ..#######.\n\
+                .#.#.###..\n\
+                .#..#.....\n\
+                ..#....#.#\n\
+                #..#....#.\n\
+                .##.#..###\n\
+                ##...#..#.\n\
+                .#....####",
+        part1: Some("33"),
+        part2: None,
+    },
+    Example {
+        input: "#.#...#.#.\n\
+                .###....#.\n\
+                .#....#...\n\
+                ##.#.#.#.#\n\
+                ....#.#.#.\n\
+                .##..###.#\n\
+                ..#...##..\n\
+                ..##....##\n\
+                ......#...\n\
+                .####.###.",
+        part1: Some("35"),
+        part2: None,
+    },
+    Example {
+        input: ".#..#..###\n\
+                ####.###.#\n\
+                ....###.#.\n\
+                ..###.##.#\n\
+                ##.##.#.#.\n\
+                ....###..#\n\
+                ..#.#..#.#\n\
+                #..#.#.###\n\
+                .##...##.#\n\
+                .....#.#..",
+        part1: Some("41"),
+        part2: None,
+    },
+    Example {
+        input: ".#..##.###...#######\n\
+                ##.############..##.\n\
+                .#.######.########.#\n\
+                .###.#######.####.#.\n\
+                #####.##.#.##.###.##\n\
+                ..#####..#.#########\n\
+                ####################\n\
+                #.####....###.#.#.##\n\
+                ##.#################\n\
+                #####.##.###..####..\n\
+                ..######..##.#######\n\
+                ####.##.####...##..#\n\
+                .#####..#.######.###\n\
+                ##...#.##########...\n\
+                #.##########.#######\n\
+                .####.#.###.###.#.##\n\
+                ....##.##.###..#####\n\
+                .#.#.###########.###\n\
+                #.#.#.#####.####.###\n\
+                ###.##.####.##.#..##",
+        part1: Some("210"),
+        part2: None,
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 10;
+    const TITLE: &'static str = "Monitoring Station";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(map).1.into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, Error> {
+        let (p, _) = part1(map);
+        Ok(part2(map, p).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_visible_from() {
-        let map = ".#..#\n\
-                   .....\n\
-                   #####\n\
-                   ....#\n\
-                   ...##"
-            .parse::<Map>()
-            .unwrap();
+        let map = EXAMPLES[0].input.parse::<Map>().unwrap();
         assert_eq!(map.visible_from(Point::new(1, 0)).len(), 8);
         assert_eq!(map.visible_from(Point::new(4, 0)).len(), 8);
         assert_eq!(map.visible_from(Point::new(0, 2)).len(), 7);
@@ -224,75 +326,15 @@ mod tests {
     #[ignore]
     #[test]
     fn test01() {
-        let map = ".#..#\n\
-                   .....\n\
-                   #####\n\
-                   ....#\n\
-                   ...##"
-            .parse::<Map>()
-            .unwrap();
+        let map = EXAMPLES[0].input.parse::<Map>().unwrap();
         assert_eq!(part1(&map), (Point::new(3, 4), 8));
-        let map = "......#.#.\n\
-                   #..#.#....\n\
-                   ..#######.\n\
-                   .#.#.###..\n\
-                   .#..#.....\n\
-                   ..#....#.#\n\
-                   #..#....#.\n\
-                   .##.#..###\n\
-                   ##...#..#.\n\
-                   .#....####"
-            .parse()
-            .unwrap();
+        let map = EXAMPLES[1].input.parse().unwrap();
         assert_eq!(part1(&map), (Point::new(5, 8), 33));
-        let map = "#.#...#.#.\n\
-                   .###....#.\n\
-                   .#....#...\n\
-                   ##.#.#.#.#\n\
-                   ....#.#.#.\n\
-                   .##..###.#\n\
-                   ..#...##..\n\
-                   ..##....##\n\
-                   ......#...\n\
-                   .####.###."
-            .parse()
-            .unwrap();
+        let map = EXAMPLES[2].input.parse().unwrap();
         assert_eq!(part1(&map), (Point::new(1, 2), 35));
-        let map = ".#..#..###\n\
-                   ####.###.#\n\
-                   ....###.#.\n\
-                   ..###.##.#\n\
-                   ##.##.#.#.\n\
-                   ....###..#\n\
-                   ..#.#..#.#\n\
-                   #..#.#.###\n\
-                   .##...##.#\n\
-                   .....#.#.."
-            .parse()
-            .unwrap();
+        let map = EXAMPLES[3].input.parse().unwrap();
         assert_eq!(part1(&map), (Point::new(6, 3), 41));
-        let map = ".#..##.###...#######\n\
-                   ##.############..##.\n\
-                   .#.######.########.#\n\
-                   .###.#######.####.#.\n\
-                   #####.##.#.##.###.##\n\
-                   ..#####..#.#########\n\
-                   ####################\n\
-                   #.####....###.#.#.##\n\
-                   ##.#################\n\
-                   #####.##.###..####..\n\
-                   ..######..##.#######\n\
-                   ####.##.####...##..#\n\
-                   .#####..#.######.###\n\
-                   ##...#.##########...\n\
-                   #.##########.#######\n\
-                   .####.#.###.###.#.##\n\
-                   ....##.##.###..#####\n\
-                   .#.#.###########.###\n\
-                   #.#.#.#####.####.###\n\
-                   ###.##.####.##.#..##"
-            .parse()
-            .unwrap();
+        let map = EXAMPLES[4].input.parse().unwrap();
         assert_eq!(part1(&map), (Point::new(11, 13), 210));
     }
 }
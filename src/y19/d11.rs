@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use std::ops::Neg;
 
 use super::intcode::Intcode;
+use crate::error::Error;
+use crate::solution::{Answer, Run, Solution};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Turn {
@@ -104,14 +106,14 @@ impl Robot {
         }
     }
 
-    fn run(&mut self, prog: &Intcode) -> Result<(), String> {
+    fn run(&mut self, prog: &Intcode) -> Result<(), Error> {
         let mut prog = prog.exec().read_vec(&[]).write_to(vec![]);
         loop {
             prog.read_next(&[self.color() as i64]);
             if let Some(color) = prog.run_to_out()? {
                 let turn = prog
                     .run_to_out()?
-                    .ok_or("Program didn't return a direction")?;
+                    .ok_or_else(|| Error::runtime("Program didn't return a direction"))?;
                 self.step(Color::from(color), Turn::from(turn));
             } else {
                 break;
@@ -121,14 +123,14 @@ impl Robot {
     }
 }
 
-fn part1(prog: &Intcode) -> Result<usize, String> {
+fn part1(prog: &Intcode) -> Result<usize, Error> {
     let mut robot = Robot::default();
     robot.run(prog)?;
     Ok(robot.visited.len())
 }
 
 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-fn part2(prog: &Intcode) -> Result<String, String> {
+fn part2(prog: &Intcode) -> Result<String, Error> {
     let mut robot = Robot::default();
     robot.visited.insert((0, 0), White);
     robot.run(prog)?;
@@ -159,14 +161,32 @@ fn part2(prog: &Intcode) -> Result<String, String> {
         .join("\n"))
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d11.txt");
-    let prog = input.parse()?;
-    let out1 = part1(&prog)?;
-    let out2 = part2(&prog)?;
-    Ok(format!("{}\n{}", out1, out2))
+pub const INPUT: &str = include_str!("input/d11.txt");
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 11;
+    const TITLE: &'static str = "Space Police";
+
+    type Input = Intcode;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(prog: &Self::Input) -> Result<Answer, Error> {
+        part1(prog).map(Answer::from)
+    }
+
+    fn part2(prog: &Self::Input) -> Result<Answer, Error> {
+        part2(prog).map(|img| Answer::image(&img))
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,6 +4,9 @@ use std::iter::Sum;
 use std::ops::{Add, AddAssign};
 use std::str::FromStr;
 
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 fn cmp(x: i64, y: i64) -> i64 {
     match x.cmp(&y) {
         Ordering::Greater => -1,
@@ -47,11 +50,10 @@ impl FromStr for Point {
                 )
             })
             .collect::<Result<Vec<_>, _>>()?;
-        Ok(Self {
-            x: pts[0],
-            y: pts[1],
-            z: pts[2],
-        })
+        match pts[..] {
+            [x, y, z] => Ok(Self { x, y, z }),
+            _ => Err(format!("Expected 3 coordinates, got {}", pts.len())),
+        }
     }
 }
 
@@ -187,47 +189,70 @@ fn part2(mut moons: Vec<Body>) -> u64 {
     [x_cnt, y_cnt, z_cnt].iter().copied().fold(1, lcm)
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d12.txt");
-    let moons = input
-        .lines()
-        .map(|line| Ok(Body::new(line.parse()?)))
-        .collect::<Result<Vec<_>, String>>()?;
-    let out1 = part1(moons.clone(), 1000);
-    let out2 = part2(moons);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d12.txt");
+
+// The puzzle only gives the energy of these after 10 and 100 steps, not 1000.
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "<x=-1, y=0, z=2>\n\
+                <x=2, y=-10, z=-7>\n\
+                <x=4, y=-8, z=8>\n\
+                <x=3, y=5, z=-1>",
+        part1: None,
+        part2: Some("2772"),
+    },
+    Example {
+        input: "<x=-8, y=-10, z=0>\n\
+                <x=5, y=5, z=10>\n\
+                <x=2, y=-7, z=3>\n\
+                <x=9, y=-8, z=-3>",
+        part1: None,
+        part2: Some("4686774924"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 12;
+    const TITLE: &'static str = "The N-Body Problem";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Body>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_lines(input)?.into_iter().map(Body::new).collect())
+    }
+
+    fn part1(moons: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(moons.clone(), 1000).into())
+    }
+
+    fn part2(moons: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(moons.clone()).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let moons = vec![
-            Body::new(Point::from((-1, 0, 2))),
-            Body::new(Point::from((2, -10, -7))),
-            Body::new(Point::from((4, -8, 8))),
-            Body::new(Point::from((3, 5, -1))),
-        ];
+        let moons = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(moons, 10), 179);
-        let moons = vec![
-            Body::new(Point::from((-8, -10, 0))),
-            Body::new(Point::from((5, 5, 10))),
-            Body::new(Point::from((2, -7, 3))),
-            Body::new(Point::from((9, -8, -3))),
-        ];
+        let moons = Solver::parse(EXAMPLES[1].input).unwrap();
         assert_eq!(part1(moons, 100), 1940);
     }
 
     #[test]
     fn test02() {
-        let moons = vec![
-            Body::new(Point::from((-8, -10, 0))),
-            Body::new(Point::from((5, 5, 10))),
-            Body::new(Point::from((2, -7, 3))),
-            Body::new(Point::from((9, -8, -3))),
-        ];
+        let moons = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(part2(moons), 2772);
+        let moons = Solver::parse(EXAMPLES[1].input).unwrap();
         assert_eq!(part2(moons), 4_686_774_924);
     }
 }
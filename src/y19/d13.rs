@@ -1,23 +1,264 @@
-fn part1() -> u64 {
-    todo!()
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
+
+use super::intcode::{Intcode, Runtime};
+use crate::error::Error;
+use crate::solution::{Answer, Run, Solution};
+
+// How long each frame stays on screen when watching.
+const FRAME: Duration = Duration::from_millis(20);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    Wall,
+    Block,
+    Paddle,
+    Ball,
+}
+
+impl TryFrom<i64> for Tile {
+    type Error = Error;
+
+    fn try_from(tile: i64) -> Result<Self, Self::Error> {
+        match tile {
+            0 => Ok(Self::Empty),
+            1 => Ok(Self::Wall),
+            2 => Ok(Self::Block),
+            3 => Ok(Self::Paddle),
+            4 => Ok(Self::Ball),
+            _ => Err(Error::runtime(format!("Invalid tile {}", tile))),
+        }
+    }
+}
+
+impl Tile {
+    const fn symbol(self) -> char {
+        match self {
+            Self::Empty => ' ',
+            Self::Wall => '#',
+            Self::Block => '=',
+            Self::Paddle => '-',
+            Self::Ball => 'o',
+        }
+    }
+}
+
+struct Arcade {
+    rt: Runtime<VecDeque<i64>, Vec<i64>>,
+    screen: HashMap<(i64, i64), Tile>,
+    score: i64,
+    ball: i64,
+    paddle: i64,
+}
+
+impl Arcade {
+    fn new(prog: &Intcode, quarters: bool) -> Self {
+        let mut rt = prog.exec().write_to(vec![]);
+        if quarters {
+            rt.set(0, 2);
+        }
+        Self {
+            rt,
+            screen: HashMap::new(),
+            score: 0,
+            ball: 0,
+            paddle: 0,
+        }
+    }
+
+    fn draw(&mut self, x: i64, y: i64, tile: i64) -> Result<(), Error> {
+        if (x, y) == (-1, 0) {
+            self.score = tile;
+            return Ok(());
+        }
+        let tile = Tile::try_from(tile)?;
+        match tile {
+            Tile::Ball => self.ball = x,
+            Tile::Paddle => self.paddle = x,
+            _ => {}
+        }
+        self.screen.insert((x, y), tile);
+        Ok(())
+    }
+
+    // Run until the game wants the joystick moved, returning whether it is
+    // still running.
+    fn update(&mut self) -> Result<bool, Error> {
+        loop {
+            if self.rt.awaiting_input() {
+                return Ok(true);
+            }
+            match self.rt.next() {
+                None => return Ok(false),
+                Some(Err(err)) => return Err(err),
+                Some(Ok(_)) if self.rt.output().len() == 3 => {
+                    let out = std::mem::take(self.rt.output_mut());
+                    self.draw(out[0], out[1], out[2])?;
+                }
+                Some(Ok(_)) => {}
+            }
+        }
+    }
+
+    fn blocks(&self) -> usize {
+        self.screen
+            .values()
+            .filter(|tile| **tile == Tile::Block)
+            .count()
+    }
+
+    fn render(&self) -> String {
+        let (mut x_max, mut y_max) = (0, 0);
+        for &(x, y) in self.screen.keys() {
+            x_max = x_max.max(x);
+            y_max = y_max.max(y);
+        }
+        let mut out = String::new();
+        for y in 0..=y_max {
+            for x in 0..=x_max {
+                let tile = self.screen.get(&(x, y)).copied().unwrap_or(Tile::Empty);
+                out.push(tile.symbol());
+            }
+            out.push('\n');
+        }
+        out + &format!("Score: {}\n", self.score)
+    }
 }
 
-fn part2() -> u64 {
-    todo!()
+fn part1(prog: &Intcode) -> Result<usize, Error> {
+    let mut arcade = Arcade::new(prog, false);
+    arcade.update()?;
+    Ok(arcade.blocks())
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let _input = include_str!("input/d13.txt");
-    let out1 = part1();
-    let out2 = part2();
-    Ok(format!("{} {}", out1, out2))
+// Play with the paddle following the ball, calling `frame` before each move
+// and once the game is over, and return the final score.
+fn play(prog: &Intcode, mut frame: impl FnMut(&Arcade) -> Result<(), Error>) -> Result<i64, Error> {
+    let mut arcade = Arcade::new(prog, true);
+    while arcade.update()? {
+        frame(&arcade)?;
+        let joystick = (arcade.ball - arcade.paddle).signum();
+        arcade.rt.read_next(&[joystick]);
+    }
+    frame(&arcade)?;
+    match arcade.blocks() {
+        0 => Ok(arcade.score),
+        n => Err(Error::no_solution(format!(
+            "Game over with {} blocks left",
+            n
+        ))),
+    }
 }
 
+fn part2(prog: &Intcode) -> Result<i64, Error> {
+    play(prog, |_| Ok(()))
+}
+
+// Play the game, drawing each frame to `out`.
+pub fn watch(prog: &Intcode, mut out: impl Write) -> Result<i64, Error> {
+    play(prog, |arcade| {
+        write!(out, "\x1b[H\x1b[2J{}", arcade.render())
+            .and_then(|()| out.flush())
+            .map_err(|err| Error::runtime(format!("Failed to write output: {}", err)))?;
+        thread::sleep(FRAME);
+        Ok(())
+    })
+}
+
+pub const INPUT: &str = include_str!("input/d13.txt");
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 13;
+    const TITLE: &'static str = "Care Package";
+
+    type Input = Intcode;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(prog: &Self::Input) -> Result<Answer, Error> {
+        part1(prog).map(Answer::from)
+    }
+
+    fn part2(prog: &Self::Input) -> Result<Answer, Error> {
+        part2(prog).map(Answer::from)
+    }
+}
+
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
-    // use super::*;
+    use super::*;
+
+    // Draws a wall, two blocks, the ball and the paddle. With quarters in, the
+    // first instruction becomes a `mul` and the game reads the joystick, then
+    // clears both blocks and scores 1000 if it was moved left.
+    fn game() -> Intcode {
+        let src = "
+                    add [one], [one], [free]
+                    out #0
+                    out #0
+                    out #1
+                    out #1
+                    out #0
+                    out #2
+                    out #2
+                    out #0
+                    out #2
+                    out #1
+                    out #1
+                    out #4
+                    out #2
+                    out #2
+                    out #3
+                    eq [free], #1, [t]
+                    jz [t], #done
+                    in [j]
+                    out #1
+                    out #0
+                    out #0
+                    out #2
+                    out #0
+                    out #0
+                    mul [j], #-1000, [s]
+                    out #-1
+                    out #0
+                    out [s]
+            done:   hlt
+            one:    .data 1
+            free:   .data 0
+            t:      .data 0
+            j:      .data 0
+            s:      .data 0
+        ";
+        Intcode::assemble(src).unwrap()
+    }
+
+    #[test]
+    fn test01() {
+        assert_eq!(part1(&game()), Ok(2));
+    }
 
-    // #[test]
-    // fn test01() {}
+    #[test]
+    fn test02() {
+        let mut frames = vec![];
+        let score = play(&game(), |arcade| {
+            frames.push(arcade.render());
+            Ok(())
+        });
+        assert_eq!(score, Ok(1000));
+        assert_eq!(
+            frames,
+            ["#==\n o \n  -\nScore: 0\n", "#  \n o \n  -\nScore: 1000\n"]
+        );
+    }
 }
@@ -1,19 +1,41 @@
-fn part1() -> u64 {
-    todo!()
+use crate::error::Error;
+use crate::solution::{Answer, Part, Run, Solution};
+
+const fn part1() -> Result<u64, Error> {
+    Err(Error::Unimplemented)
 }
 
-fn part2() -> u64 {
-    todo!()
+const fn part2() -> Result<u64, Error> {
+    Err(Error::Unimplemented)
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let _input = include_str!("input/d14.txt");
-    let out1 = part1();
-    let out2 = part2();
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d14.txt");
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 14;
+    const TITLE: &'static str = "Space Stoichiometry";
+    const PARTS: &'static [Part] = &[];
+
+    type Input = ();
+
+    fn parse(_input: &str) -> Result<Self::Input, Error> {
+        Ok(())
+    }
+
+    fn part1((): &Self::Input) -> Result<Answer, Error> {
+        Ok(part1()?.into())
+    }
+
+    fn part2((): &Self::Input) -> Result<Answer, Error> {
+        Ok(part2()?.into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     // use super::*;
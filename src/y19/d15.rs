@@ -1,23 +1,305 @@
-fn part1() -> u64 {
-    todo!()
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::io::{self, Write};
+
+use super::intcode::{Intcode, Runtime};
+use crate::error::Error;
+use crate::solution::{Answer, Run, Solution};
+
+type Pos = (i64, i64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dir {
+    North = 1,
+    South = 2,
+    West = 3,
+    East = 4,
+}
+use Dir::*;
+
+impl Dir {
+    const ALL: [Self; 4] = [North, South, West, East];
+
+    const fn opposite(self) -> Self {
+        match self {
+            North => South,
+            South => North,
+            West => East,
+            East => West,
+        }
+    }
+
+    const fn step(self, (x, y): Pos) -> Pos {
+        match self {
+            North => (x, y - 1),
+            South => (x, y + 1),
+            West => (x - 1, y),
+            East => (x + 1, y),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Wall,
+    Open,
+    Oxygen,
+}
+
+// Something that moves one cell at a time, reporting what it ran into or
+// `None` if it hit a wall and stayed put.
+trait Droid {
+    fn step(&mut self, dir: Dir) -> Result<Option<Cell>, Error>;
+}
+
+impl Droid for Runtime<VecDeque<i64>, io::Sink> {
+    fn step(&mut self, dir: Dir) -> Result<Option<Cell>, Error> {
+        self.read_next(&[dir as i64]);
+        match self.run_to_out()? {
+            Some(0) => Ok(None),
+            Some(1) => Ok(Some(Cell::Open)),
+            Some(2) => Ok(Some(Cell::Oxygen)),
+            Some(status) => Err(Error::runtime(format!("Invalid status {}", status))),
+            None => Err(Error::runtime("Droid halted")),
+        }
+    }
+}
+
+// The ship as mapped by the droid, which starts at the origin.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Ship {
+    cells: HashMap<Pos, Cell>,
+}
+
+impl Ship {
+    fn explore(droid: &mut impl Droid) -> Result<Self, Error> {
+        let mut ship = Self::default();
+        ship.cells.insert((0, 0), Cell::Open);
+        ship.visit(droid, (0, 0))?;
+        Ok(ship)
+    }
+
+    // Map every cell reachable from `pos`, leaving the droid back there.
+    fn visit(&mut self, droid: &mut impl Droid, pos: Pos) -> Result<(), Error> {
+        for dir in Dir::ALL {
+            let next = dir.step(pos);
+            if self.cells.contains_key(&next) {
+                continue;
+            }
+            match droid.step(dir)? {
+                None => {
+                    self.cells.insert(next, Cell::Wall);
+                }
+                Some(cell) => {
+                    self.cells.insert(next, cell);
+                    self.visit(droid, next)?;
+                    if droid.step(dir.opposite())?.is_none() {
+                        return Err(Error::runtime("Droid could not retrace its steps"));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn oxygen(&self) -> Result<Pos, Error> {
+        self.cells
+            .iter()
+            .find(|(_, cell)| **cell == Cell::Oxygen)
+            .map(|(pos, _)| *pos)
+            .ok_or_else(|| Error::no_solution("No oxygen system found"))
+    }
+
+    // The fewest moves from the droid's starting point to the oxygen system.
+    fn oxygen_distance(&self) -> Result<usize, Error> {
+        let oxygen = self.oxygen()?;
+        Ok(self.distances((0, 0))[&oxygen])
+    }
+
+    // How many minutes it takes oxygen to spread to every open cell.
+    fn fill_time(&self) -> Result<usize, Error> {
+        let dist = self.distances(self.oxygen()?);
+        Ok(dist.values().copied().max().unwrap_or(0))
+    }
+
+    // The length of the shortest path from `from` to every open cell.
+    fn distances(&self, from: Pos) -> HashMap<Pos, usize> {
+        let mut dist = HashMap::new();
+        dist.insert(from, 0);
+        let mut queue = VecDeque::from(vec![from]);
+        while let Some(pos) = queue.pop_front() {
+            let d = dist[&pos] + 1;
+            for dir in Dir::ALL {
+                let next = dir.step(pos);
+                if !matches!(self.cells.get(&next), None | Some(Cell::Wall))
+                    && !dist.contains_key(&next)
+                {
+                    dist.insert(next, d);
+                    queue.push_back(next);
+                }
+            }
+        }
+        dist
+    }
+}
+
+impl fmt::Display for Ship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xs = self.cells.keys().map(|(x, _)| *x);
+        let ys = self.cells.keys().map(|(_, y)| *y);
+        let (x_min, x_max) = (xs.clone().min().unwrap_or(0), xs.max().unwrap_or(0));
+        let (y_min, y_max) = (ys.clone().min().unwrap_or(0), ys.max().unwrap_or(0));
+        for y in y_min..=y_max {
+            let row = (x_min..=x_max)
+                .map(|x| match self.cells.get(&(x, y)) {
+                    _ if (x, y) == (0, 0) => 'D',
+                    Some(Cell::Wall) => '#',
+                    Some(Cell::Open) => '.',
+                    Some(Cell::Oxygen) => 'O',
+                    None => ' ',
+                })
+                .collect::<String>();
+            writeln!(f, "{}", row.trim_end())?;
+        }
+        Ok(())
+    }
 }
 
-fn part2() -> u64 {
-    todo!()
+fn part1(prog: &Intcode) -> Result<usize, Error> {
+    Ship::explore(&mut prog.exec())?.oxygen_distance()
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let _input = include_str!("input/d15.txt");
-    let out1 = part1();
-    let out2 = part2();
-    Ok(format!("{} {}", out1, out2))
+fn part2(prog: &Intcode) -> Result<usize, Error> {
+    Ship::explore(&mut prog.exec())?.fill_time()
 }
 
+// Explore the ship and print the map.
+pub fn map(prog: &Intcode, mut out: impl Write) -> Result<(), Error> {
+    let ship = Ship::explore(&mut prog.exec())?;
+    write!(out, "{}", ship)
+        .map_err(|err| Error::runtime(format!("Failed to write output: {}", err)))
+}
+
+pub const INPUT: &str = include_str!("input/d15.txt");
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 15;
+    const TITLE: &'static str = "Oxygen System";
+
+    type Input = Intcode;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(prog: &Self::Input) -> Result<Answer, Error> {
+        part1(prog).map(Answer::from)
+    }
+
+    fn part2(prog: &Self::Input) -> Result<Answer, Error> {
+        part2(prog).map(Answer::from)
+    }
+}
+
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
-    // use super::*;
+    use super::*;
+
+    // A droid walking a map drawn as text, starting at the `D`.
+    struct Maze {
+        rows: Vec<&'static [u8]>,
+        pos: Pos,
+    }
+
+    impl Maze {
+        fn new(map: &'static str) -> Self {
+            let rows = map.lines().map(str::as_bytes).collect::<Vec<_>>();
+            let (y, row) = rows
+                .iter()
+                .enumerate()
+                .find(|(_, row)| row.contains(&b'D'))
+                .unwrap();
+            let x = row.iter().position(|c| *c == b'D').unwrap();
+            Self {
+                rows,
+                pos: (x as i64, y as i64),
+            }
+        }
+    }
+
+    impl Droid for Maze {
+        fn step(&mut self, dir: Dir) -> Result<Option<Cell>, Error> {
+            let (x, y) = dir.step(self.pos);
+            let c = self.rows[y as usize]
+                .get(x as usize)
+                .copied()
+                .unwrap_or(b' ');
+            let cell = match c {
+                b'.' | b'D' => Cell::Open,
+                b'O' => Cell::Oxygen,
+                _ => return Ok(None),
+            };
+            self.pos = (x, y);
+            Ok(Some(cell))
+        }
+    }
+
+    // A droid program for a ship with the oxygen system just east of the
+    // starting point and walls everywhere else.
+    fn corridor() -> Intcode {
+        let src = "
+            loop:   in [d]
+                    jnz [x], #east
+                    eq [d], #4, [t]
+                    jz [t], #wall
+                    add #1, #0, [x]
+                    out #2
+                    jnz #1, #loop
+            east:   eq [d], #3, [t]
+                    jz [t], #wall
+                    add #0, #0, [x]
+                    out #1
+                    jnz #1, #loop
+            wall:   out #0
+                    jnz #1, #loop
+            d:      .data 0
+            x:      .data 0
+            t:      .data 0
+        ";
+        Intcode::assemble(src).unwrap()
+    }
+
+    const MAP: &str = " ##
+#D.##
+#.#..#
+#.O.#
+ ###
+";
+
+    #[test]
+    fn test01() {
+        let ship = Ship::explore(&mut Maze::new(MAP)).unwrap();
+        assert_eq!(ship.oxygen_distance(), Ok(3));
+        assert_eq!(ship.to_string(), MAP);
+        assert_eq!(part1(&corridor()), Ok(1));
+    }
+
+    #[test]
+    fn test02() {
+        let ship = Ship::explore(&mut Maze::new(MAP)).unwrap();
+        assert_eq!(ship.fill_time(), Ok(4));
+        assert_eq!(part2(&corridor()), Ok(1));
+    }
 
-    // #[test]
-    // fn test01() {}
+    #[test]
+    fn test_map() {
+        let mut out = vec![];
+        map(&corridor(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), " ##\n#DO#\n ##\n");
+    }
 }
@@ -1,23 +1,140 @@
-fn part1() -> u64 {
-    todo!()
+use super::intcode::Intcode;
+use super::network::{Action, Nat, Network, Packet};
+use crate::error::Error;
+use crate::solution::{Answer, Run, Solution};
+
+const MACHINES: usize = 50;
+const NAT: i64 = 255;
+const BUDGET: u64 = 100_000_000;
+
+// Stops at the first packet sent to the NAT.
+struct FirstPacket;
+
+impl Nat for FirstPacket {
+    type Output = i64;
+
+    fn receive(&mut self, packet: Packet) -> Action<i64> {
+        if packet.dest == NAT {
+            Action::Stop(packet.y)
+        } else {
+            Action::Continue
+        }
+    }
 }
 
-fn part2() -> u64 {
-    todo!()
+// Wakes the network by resending the last packet it received to address 0,
+// and stops when it sends the same `y` twice in a row.
+#[derive(Default)]
+struct Wake {
+    last: Option<Packet>,
+    sent: Option<i64>,
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let _input = include_str!("input/d23.txt");
-    let out1 = part1();
-    let out2 = part2();
-    Ok(format!("{} {}", out1, out2))
+impl Nat for Wake {
+    type Output = i64;
+
+    fn receive(&mut self, packet: Packet) -> Action<i64> {
+        if packet.dest == NAT {
+            self.last = Some(packet);
+        }
+        Action::Continue
+    }
+
+    fn idle(&mut self) -> Action<i64> {
+        match self.last {
+            Some(packet) if self.sent == Some(packet.y) => Action::Stop(packet.y),
+            Some(packet) => {
+                self.sent = Some(packet.y);
+                Action::Send(Packet { dest: 0, ..packet })
+            }
+            None => Action::Continue,
+        }
+    }
 }
 
+fn part1(prog: &Intcode, n: usize) -> Result<i64, Error> {
+    Network::boot(prog, n).run(&mut FirstPacket, BUDGET)
+}
+
+fn part2(prog: &Intcode, n: usize) -> Result<i64, Error> {
+    Network::boot(prog, n).run(&mut Wake::default(), BUDGET)
+}
+
+pub const INPUT: &str = include_str!("input/d23.txt");
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 23;
+    const TITLE: &'static str = "Category Six";
+
+    type Input = Intcode;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(prog: &Self::Input) -> Result<Answer, Error> {
+        part1(prog, MACHINES).map(Answer::from)
+    }
+
+    fn part2(prog: &Self::Input) -> Result<Answer, Error> {
+        part2(prog, MACHINES).map(Answer::from)
+    }
+}
+
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
-    // use super::*;
+    use super::*;
+
+    // Machine 0 starts a chain of packets through every machine, each adding
+    // 1 to `y` up to 10, with the last machine sending to the NAT.
+    fn chain(n: usize) -> Intcode {
+        let src = format!(
+            "
+                    in [addr]
+                    jnz [addr], #loop
+                    out #1
+                    out #0
+                    out #1
+            loop:   in [x]
+                    eq [x], #-1, [t]
+                    jnz [t], #loop
+                    in [y]
+                    add [addr], #1, [dest]
+                    eq [dest], #{}, [t]
+                    jz [t], #inc
+                    add #255, #0, [dest]
+            inc:    lt [y], #10, [t]
+                    jz [t], #send
+                    add [y], #1, [y]
+            send:   out [dest]
+                    out [x]
+                    out [y]
+                    jz #0, #loop
+            addr:   .data 0
+            x:      .data 0
+            y:      .data 0
+            t:      .data 0
+            dest:   .data 0
+            ",
+            n
+        );
+        Intcode::assemble(&src).unwrap()
+    }
+
+    #[test]
+    fn test01() {
+        assert_eq!(part1(&chain(3), 3), Ok(3));
+        assert_eq!(part1(&chain(5), 5), Ok(5));
+    }
 
-    // #[test]
-    // fn test01() {}
+    #[test]
+    fn test02() {
+        // The NAT delivers 3, 6, 9, 10 and then 10 again.
+        assert_eq!(part2(&chain(3), 3), Ok(10));
+    }
 }
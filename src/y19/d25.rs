@@ -1,23 +1,547 @@
-fn part1() -> u64 {
-    todo!()
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::io::{BufRead, Write};
+
+use super::ascii::{Ascii, Reply};
+use super::intcode::{Intcode, Snapshot};
+use crate::error::Error;
+use crate::solution::{Answer, Run, Solution};
+
+// Instructions a single command may take before the droid is assumed to be
+// stuck in a loop.
+const BUDGET: u64 = 1_000_000;
+
+// A running game that can be saved and restored, so that dangerous moves can
+// be tried out first.
+trait Game {
+    type Save;
+
+    fn command(&mut self, cmd: &str) -> Result<Reply, Error>;
+    fn save(&mut self) -> Self::Save;
+    fn load(&mut self, save: &Self::Save);
+}
+
+impl Game for Ascii {
+    type Save = Snapshot;
+
+    fn command(&mut self, cmd: &str) -> Result<Reply, Error> {
+        Self::command(self, cmd)
+    }
+
+    fn save(&mut self) -> Snapshot {
+        self.runtime().snapshot()
+    }
+
+    fn load(&mut self, save: &Snapshot) {
+        self.runtime().restore(save);
+    }
+}
+
+// The names of the rooms described in `text`, in order.
+fn room_names(text: &str) -> impl Iterator<Item = &str> {
+    text.lines()
+        .filter_map(|line| line.strip_prefix("== ")?.strip_suffix(" =="))
+}
+
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+struct Room {
+    name: String,
+    doors: Vec<String>,
+    items: Vec<String>,
+}
+
+impl Room {
+    // The last room described in `text`.
+    fn parse(text: &str) -> Result<Self, Error> {
+        let name = room_names(text)
+            .last()
+            .ok_or_else(|| Error::runtime(format!("Expected a room, got {:?}", text.trim())))?;
+        let mut room = Self {
+            name: name.into(),
+            ..Self::default()
+        };
+        let desc = &text[text.rfind(&format!("== {} ==", name)).unwrap()..];
+        let mut list = None;
+        for line in desc.lines() {
+            match (line, line.strip_prefix("- ")) {
+                ("Doors here lead:", _) => list = Some(&mut room.doors),
+                ("Items here:", _) => list = Some(&mut room.items),
+                (_, Some(entry)) => {
+                    if let Some(list) = &mut list {
+                        list.push(entry.into());
+                    }
+                }
+                ("", None) => {}
+                _ => list = None,
+            }
+        }
+        Ok(room)
+    }
 }
 
-fn part2() -> u64 {
-    todo!()
+// The rooms in the order they were found, and where each of their doors leads.
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+struct Map {
+    rooms: Vec<Room>,
+    doors: HashMap<(String, String), String>,
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let _input = include_str!("input/d25.txt");
-    let out1 = part1();
-    let out2 = part2();
-    Ok(format!("{} {}", out1, out2))
+impl Map {
+    fn room(&self, name: &str) -> Option<&Room> {
+        self.rooms.iter().find(|room| room.name == name)
+    }
+
+    fn leads_to(&self, room: &str, door: &str) -> Option<&str> {
+        self.doors
+            .get(&(room.into(), door.into()))
+            .map(String::as_str)
+    }
+
+    // The room and door leading to the pressure-sensitive floor, the only
+    // place the droid gets thrown back from.
+    fn checkpoint(&self) -> Option<(&str, &str)> {
+        self.rooms.iter().find_map(|room| {
+            room.doors.iter().find_map(|door| {
+                let next = self.leads_to(&room.name, door)?;
+                self.room(next)
+                    .is_none()
+                    .then_some((room.name.as_str(), door.as_str()))
+            })
+        })
+    }
+
+    // The doors to go through to get from one room to another.
+    fn path(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        let mut prev = HashMap::new();
+        let mut queue = VecDeque::from(vec![from]);
+        while let Some(name) = queue.pop_front() {
+            if name == to {
+                let mut path = vec![];
+                let mut name = to;
+                while let Some(&(room, door)) = prev.get(name) {
+                    path.push(String::from(door));
+                    name = room;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for door in &self.room(name)?.doors {
+                match self.leads_to(name, door) {
+                    Some(next)
+                        if next != from
+                            && !prev.contains_key(next)
+                            && self.room(next).is_some() =>
+                    {
+                        prev.insert(next, (name, door.as_str()));
+                        queue.push_back(next);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        None
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for room in &self.rooms {
+            let doors = room
+                .doors
+                .iter()
+                .map(|door| {
+                    let next = self.leads_to(&room.name, door).unwrap_or("?");
+                    format!("{} -> {}", door, next)
+                })
+                .collect::<Vec<_>>();
+            write!(f, "{}: {}", room.name, doors.join(", "))?;
+            if !room.items.is_empty() {
+                write!(f, "; items: {}", room.items.join(", "))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+const fn opposite(dir: &str) -> &'static str {
+    match dir.as_bytes() {
+        b"north" => "south",
+        b"south" => "north",
+        b"east" => "west",
+        _ => "east",
+    }
+}
+
+struct Explorer<'a, G> {
+    game: &'a mut G,
+    items: Vec<String>,
+    map: Map,
+}
+
+impl<'a, G: Game> Explorer<'a, G> {
+    fn new(game: &'a mut G) -> Self {
+        Self {
+            game,
+            items: vec![],
+            map: Map::default(),
+        }
+    }
+
+    fn command(&mut self, cmd: &str) -> Result<Reply, Error> {
+        let reply = self.game.command(cmd)?;
+        if reply.halted {
+            return Err(Error::runtime(format!(
+                "Game over after {:?}: {}",
+                cmd,
+                reply.text.trim()
+            )));
+        }
+        Ok(reply)
+    }
+
+    // Whether the droid can still leave `room` after taking `item`. Items
+    // that end the game, hang the machine or stop the droid moving are not.
+    fn is_safe(&mut self, room: &Room, item: &str) -> bool {
+        let save = self.game.save();
+        let safe = self.command(&format!("take {}", item)).is_ok()
+            && room.doors.first().is_none_or(|door| {
+                self.command(door)
+                    .is_ok_and(|reply| room_names(&reply.text).any(|name| name != room.name))
+            });
+        self.game.load(&save);
+        safe
+    }
+
+    // Visit and map every room reachable from `room`, picking up every safe
+    // item, and return to it.
+    fn explore(&mut self, room: &Room) -> Result<(), Error> {
+        self.map.rooms.push(room.clone());
+        for item in &room.items {
+            if self.is_safe(room, item) {
+                self.command(&format!("take {}", item))?;
+                self.items.push(item.clone());
+            }
+        }
+        for door in &room.doors {
+            let reply = self.command(door)?;
+            let next = Room::parse(&reply.text)?;
+            let key = (room.name.clone(), door.clone());
+            if next.name == room.name {
+                // Thrown back by the pressure-sensitive floor.
+                let floor = room_names(&reply.text).next().unwrap_or("?");
+                self.map.doors.insert(key, floor.into());
+                continue;
+            }
+            self.map.doors.insert(key, next.name.clone());
+            if self.map.room(&next.name).is_none() {
+                self.explore(&next)?;
+            }
+            self.command(opposite(door))?;
+        }
+        Ok(())
+    }
+
+    // Try subsets of the items on the floor until one weighs the same as a
+    // droid, skipping any that contain a subset found to be too heavy or are
+    // contained in one found to be too light.
+    fn crack(&mut self, door: &str) -> Result<String, Error> {
+        let items = self.items.clone();
+        let mut held = (1_usize << items.len()) - 1;
+        let (mut heavy, mut light) = (vec![], vec![]);
+        for set in 0..1_usize << items.len() {
+            if heavy.iter().any(|h| set & h == *h) || light.iter().any(|l| set & l == set) {
+                continue;
+            }
+            for (idx, item) in items.iter().enumerate() {
+                let bit = 1 << idx;
+                if set & bit != held & bit {
+                    let verb = if set & bit != 0 { "take" } else { "drop" };
+                    self.command(&format!("{} {}", verb, item))?;
+                }
+            }
+            held = set;
+            let reply = self.game.command(door)?;
+            if reply.text.contains("heavier than the detected") {
+                light.push(set);
+            } else if reply.text.contains("lighter than the detected") {
+                heavy.push(set);
+            } else {
+                return reply
+                    .text
+                    .split("typing ")
+                    .nth(1)
+                    .and_then(|rest| rest.split_whitespace().next())
+                    .map(String::from)
+                    .ok_or_else(|| {
+                        Error::runtime(format!("No password in {:?}", reply.text.trim()))
+                    });
+            }
+        }
+        Err(Error::no_solution(
+            "No set of items gets past the pressure-sensitive floor",
+        ))
+    }
 }
 
+fn solve<G: Game>(game: &mut G, intro: &Reply) -> Result<String, Error> {
+    let start = Room::parse(&intro.text)?;
+    let mut explorer = Explorer::new(game);
+    explorer.explore(&start)?;
+    let (checkpoint, door) = explorer
+        .map
+        .checkpoint()
+        .ok_or_else(|| Error::no_solution("No pressure-sensitive floor found"))?;
+    let door = door.to_string();
+    let path = explorer
+        .map
+        .path(&start.name, checkpoint)
+        .ok_or_else(|| Error::no_solution("No way back to the security checkpoint"))?;
+    for dir in &path {
+        explorer.command(dir)?;
+    }
+    explorer.crack(&door)
+}
+
+fn part1(prog: &Intcode) -> Result<String, Error> {
+    let mut game = Ascii::new(prog).with_budget(BUDGET);
+    let intro = game.read()?;
+    solve(&mut game, &intro)
+}
+
+// Explore the ship, picking up what is safe to carry, and print the map.
+pub fn map(prog: &Intcode, mut out: impl Write) -> Result<(), Error> {
+    let mut game = Ascii::new(prog).with_budget(BUDGET);
+    let start = Room::parse(&game.read()?.text)?;
+    let mut explorer = Explorer::new(&mut game);
+    explorer.explore(&start)?;
+    write!(out, "{}", explorer.map)
+        .map_err(|err| Error::runtime(format!("Failed to write output: {}", err)))
+}
+
+// Play the game, reading commands from `input` and echoing the game's output.
+pub fn play(prog: &Intcode, mut input: impl BufRead, mut output: impl Write) -> Result<(), Error> {
+    let mut game = Ascii::new(prog);
+    let mut reply = game.read()?;
+    loop {
+        write!(output, "{}", reply.text)
+            .and_then(|()| output.flush())
+            .map_err(|err| Error::runtime(format!("Failed to write output: {}", err)))?;
+        if reply.halted {
+            return Ok(());
+        }
+        let mut line = String::new();
+        if input
+            .read_line(&mut line)
+            .map_err(|err| Error::runtime(format!("Failed to read input: {}", err)))?
+            == 0
+        {
+            return Ok(());
+        }
+        reply = game.command(line.trim_end())?;
+    }
+}
+
+pub const INPUT: &str = include_str!("input/d25.txt");
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 25;
+    const TITLE: &'static str = "Cryostasis";
+
+    type Input = Intcode;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(prog: &Self::Input) -> Result<Answer, Error> {
+        part1(prog).map(Answer::from)
+    }
+
+    fn part2(_: &Self::Input) -> Result<Answer, Error> {
+        Ok("".into())
+    }
+}
+
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
-    // use super::*;
+    use super::*;
+
+    // Name, doors and items of each room of a small ship.
+    const ROOMS: [(&str, &[(&str, usize)], &[&str]); 5] = [
+        ("Hull Breach", &[("north", 1), ("east", 2)], &[]),
+        ("Kitchen", &[("south", 0)], &["mug", "molten lava"]),
+        (
+            "Hallway",
+            &[("west", 0), ("east", 3), ("north", 4)],
+            &["infinite loop", "giant electromagnet", "coin"],
+        ),
+        ("Security Checkpoint", &[("west", 2), ("east", 5)], &[]),
+        ("Stables", &[("south", 2)], &["hay"]),
+    ];
+
+    // A stand-in for the Intcode game, where the floor lets through a droid
+    // carrying exactly the mug and the hay.
+    #[derive(Clone)]
+    struct Ship {
+        room: usize,
+        items: Vec<Vec<&'static str>>,
+        held: Vec<&'static str>,
+    }
+
+    impl Ship {
+        fn new() -> Self {
+            Self {
+                room: 0,
+                items: ROOMS.iter().map(|(_, _, items)| items.to_vec()).collect(),
+                held: vec![],
+            }
+        }
+
+        fn describe(&self) -> String {
+            let (name, doors, _) = ROOMS[self.room];
+            let mut text = format!("\n\n\n== {} ==\nA room.\n\nDoors here lead:\n", name);
+            for (door, _) in doors {
+                text += &format!("- {}\n", door);
+            }
+            if !self.items[self.room].is_empty() {
+                text += "\nItems here:\n";
+                for item in &self.items[self.room] {
+                    text += &format!("- {}\n", item);
+                }
+            }
+            text + "\nCommand?\n"
+        }
+
+        fn weight(&self) -> i64 {
+            let weights = [("mug", 1), ("coin", 2), ("hay", 4)];
+            self.held
+                .iter()
+                .map(|item| weights.iter().find(|(name, _)| name == item).unwrap().1)
+                .sum()
+        }
+    }
+
+    fn reply(text: String, halted: bool) -> Result<Reply, Error> {
+        Ok(Reply {
+            text,
+            answer: None,
+            halted,
+        })
+    }
+
+    impl Game for Ship {
+        type Save = Self;
+
+        fn command(&mut self, cmd: &str) -> Result<Reply, Error> {
+            if let Some(item) = cmd.strip_prefix("take ") {
+                match item {
+                    "molten lava" => return reply("You melt!\n".into(), true),
+                    "infinite loop" => return Err(Error::vm(0, "Still running")),
+                    _ => {}
+                }
+                let idx = self.items[self.room].iter().position(|i| *i == item);
+                self.held.push(self.items[self.room].remove(idx.unwrap()));
+                return reply(format!("\nYou take the {}.\n\nCommand?\n", item), false);
+            }
+            if let Some(item) = cmd.strip_prefix("drop ") {
+                let idx = self.held.iter().position(|i| *i == item);
+                self.items[self.room].push(self.held.remove(idx.unwrap()));
+                return reply(format!("\nYou drop the {}.\n\nCommand?\n", item), false);
+            }
+            if self.held.contains(&"giant electromagnet") {
+                return reply("\nYou can't move!!\n\nCommand?\n".into(), false);
+            }
+            let (_, doors, _) = ROOMS[self.room];
+            let &(_, next) = doors.iter().find(|(door, _)| *door == cmd).unwrap();
+            if next < ROOMS.len() {
+                self.room = next;
+                return reply(self.describe(), false);
+            }
+            let floor = "\n\n\n== Pressure-Sensitive Floor ==\nAnalyzing...\n\n";
+            match self.weight() {
+                5 => reply(
+                    format!("{}You may proceed by typing 1234 on the keypad.\n", floor),
+                    true,
+                ),
+                w => reply(
+                    format!(
+                        "{}Alert! Droids on this ship are {} than the detected value!{}",
+                        floor,
+                        if w < 5 { "heavier" } else { "lighter" },
+                        self.describe()
+                    ),
+                    false,
+                ),
+            }
+        }
+
+        fn save(&mut self) -> Self {
+            self.clone()
+        }
+
+        fn load(&mut self, save: &Self) {
+            *self = save.clone();
+        }
+    }
+
+    #[test]
+    fn test_room() {
+        let ship = Ship::new();
+        assert_eq!(
+            Room::parse(&ship.describe()),
+            Ok(Room {
+                name: "Hull Breach".into(),
+                doors: vec!["north".into(), "east".into()],
+                items: vec![],
+            })
+        );
+        assert!(Room::parse("\nYou take the mug.\n").is_err());
+    }
+
+    #[test]
+    fn test01() {
+        let mut ship = Ship::new();
+        let intro = reply(ship.describe(), false).unwrap();
+        assert_eq!(solve(&mut ship, &intro), Ok("1234".into()));
+        ship.held.sort_unstable();
+        assert_eq!(ship.held, ["hay", "mug"]);
+        assert_eq!(ship.items[1], ["molten lava"]);
+        assert_eq!(ship.items[2], ["infinite loop", "giant electromagnet"]);
+        assert_eq!(ship.items[3], ["coin"]);
+    }
 
-    // #[test]
-    // fn test01() {}
+    #[test]
+    fn test_map() {
+        let mut ship = Ship::new();
+        let start = Room::parse(&ship.describe()).unwrap();
+        let mut explorer = Explorer::new(&mut ship);
+        explorer.explore(&start).unwrap();
+        assert_eq!(
+            explorer.map.to_string(),
+            "Hull Breach: north -> Kitchen, east -> Hallway\n\
+             Kitchen: south -> Hull Breach; items: mug, molten lava\n\
+             Hallway: west -> Hull Breach, east -> Security Checkpoint, north -> Stables; \
+             items: infinite loop, giant electromagnet, coin\n\
+             Security Checkpoint: west -> Hallway, east -> Pressure-Sensitive Floor\n\
+             Stables: south -> Hallway; items: hay\n"
+        );
+        assert_eq!(
+            explorer.map.checkpoint(),
+            Some(("Security Checkpoint", "east"))
+        );
+        assert_eq!(
+            explorer.map.path("Stables", "Kitchen"),
+            Some(vec!["south".into(), "west".into(), "north".into()])
+        );
+        assert_eq!(
+            explorer.map.path("Security Checkpoint", "Stables"),
+            Some(vec!["west".into(), "north".into()])
+        );
+    }
 }
@@ -1,14 +1,97 @@
-use std::collections::HashMap;
+use std::collections::hash_map::{Entry, HashMap};
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fmt;
 use std::io;
 use std::ops::Index;
 use std::str::FromStr;
+use std::sync::mpsc;
 
-fn ints_to_bytes(xs: &[i64]) -> Vec<u8> {
-    xs.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect()
+use crate::error::{column, Error};
+
+// Where a runtime's input comes from. Returning `None` makes the `in`
+// instruction fail, leaving the runtime ready to retry it.
+pub trait InputProvider {
+    fn next_input(&mut self) -> Option<i64>;
+}
+
+// Where a runtime's output goes. Returning `false` makes the `out`
+// instruction fail.
+pub trait OutputSink {
+    fn push_output(&mut self, val: i64) -> bool;
+}
+
+impl InputProvider for VecDeque<i64> {
+    fn next_input(&mut self) -> Option<i64> {
+        self.pop_front()
+    }
+}
+
+// Blocks until a value arrives or every sender is gone.
+impl InputProvider for mpsc::Receiver<i64> {
+    fn next_input(&mut self) -> Option<i64> {
+        self.recv().ok()
+    }
+}
+
+impl<F: FnMut() -> Option<i64>> InputProvider for F {
+    fn next_input(&mut self) -> Option<i64> {
+        self()
+    }
+}
+
+impl OutputSink for Vec<i64> {
+    fn push_output(&mut self, val: i64) -> bool {
+        self.push(val);
+        true
+    }
+}
+
+impl OutputSink for VecDeque<i64> {
+    fn push_output(&mut self, val: i64) -> bool {
+        self.push_back(val);
+        true
+    }
+}
+
+impl OutputSink for mpsc::Sender<i64> {
+    fn push_output(&mut self, val: i64) -> bool {
+        self.send(val).is_ok()
+    }
+}
+
+// Discards the output.
+impl OutputSink for io::Sink {
+    fn push_output(&mut self, _: i64) -> bool {
+        true
+    }
 }
 
+// Mnemonic, number of parameters and the parameter written to, if any, of
+// each opcode.
+const OPCODES: [(i64, &str, u32, Option<u32>); 10] = [
+    (1, "add", 3, Some(3)),
+    (2, "mul", 3, Some(3)),
+    (3, "in", 1, Some(1)),
+    (4, "out", 1, None),
+    (5, "jnz", 2, None),
+    (6, "jz", 2, None),
+    (7, "lt", 3, Some(3)),
+    (8, "eq", 3, Some(3)),
+    (9, "arb", 1, None),
+    (99, "hlt", 0, None),
+];
+
+// The mnemonics of all the opcodes.
+pub fn mnemonics() -> impl Iterator<Item = &'static str> {
+    OPCODES.iter().map(|&(_, name, ..)| name)
+}
+
+// Number of values per `.data` line in a disassembly.
+const DATA_WIDTH: usize = 8;
+
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
-enum ParamMode {
+pub enum ParamMode {
     Position,
     Immediate,
     Relative,
@@ -16,16 +99,89 @@ enum ParamMode {
 use ParamMode::*;
 
 impl ParamMode {
-    fn new(op: i64, param: u32) -> Self {
-        match (op / 10_i64.pow(param + 1)) % 10 {
-            0 => Position,
-            1 => Immediate,
-            2 => Relative,
-            _ => unreachable!(),
+    const fn new(op: i64, param: u32) -> Option<Self> {
+        Self::from_digit((op / 10_i64.pow(param + 1)) % 10)
+    }
+
+    const fn from_digit(digit: i64) -> Option<Self> {
+        match digit {
+            0 => Some(Position),
+            1 => Some(Immediate),
+            2 => Some(Relative),
+            _ => None,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Param {
+    pub mode: ParamMode,
+    pub val: i64,
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            Position => write!(f, "[{}]", self.val),
+            Immediate => write!(f, "#{}", self.val),
+            Relative if self.val < 0 => write!(f, "rb{}", self.val),
+            Relative => write!(f, "rb+{}", self.val),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Line {
+    Instr {
+        addr: u64,
+        op: &'static str,
+        params: Vec<Param>,
+    },
+    Data {
+        addr: u64,
+        vals: Vec<i64>,
+    },
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Instr { addr, op, params } if params.is_empty() => {
+                write!(f, "{:>4}: {}", addr, op)
+            }
+            Self::Instr { addr, op, params } => {
+                let params = params.iter().map(Param::to_string).collect::<Vec<_>>();
+                write!(f, "{:>4}: {} {}", addr, op, params.join(", "))
+            }
+            Self::Data { addr, vals } => {
+                let vals = vals.iter().map(i64::to_string).collect::<Vec<_>>();
+                write!(f, "{:>4}: .data {}", addr, vals.join(", "))
+            }
         }
     }
 }
 
+// Decode the instruction at `addr` without running it. Anything that is not a
+// well-formed instruction is treated as data.
+fn decode(fetch: impl Fn(u64) -> Option<i64>, addr: u64) -> Option<(&'static str, Vec<Param>)> {
+    let instr = fetch(addr)?;
+    let &(_, op, nparams, out) = OPCODES.iter().find(|(op, ..)| *op == instr % 100)?;
+    if instr < 0 || instr / 10_i64.pow(nparams + 2) != 0 {
+        return None;
+    }
+    let params = (1..=nparams)
+        .map(|param| {
+            let mode = ParamMode::from_digit((instr / 10_i64.pow(param + 1)) % 10)?;
+            if mode == Immediate && out == Some(param) {
+                return None;
+            }
+            let val = fetch(addr + u64::from(param))?;
+            Some(Param { mode, val })
+        })
+        .collect::<Option<_>>()?;
+    Some((op, params))
+}
+
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 enum BinOp {
     Add,
@@ -71,27 +227,27 @@ enum Opcode {
 use Opcode::*;
 
 impl Opcode {
-    fn new(mem: &Memory) -> Result<Self, String> {
+    fn new(mem: &Memory) -> Result<Self, Error> {
         match mem.instr() % 100 {
             op @ (1 | 2) => Ok(Arith(
                 if op == 1 { Add } else { Mul },
-                mem.in_param(1),
-                mem.in_param(2),
-                mem.out_param(3),
+                mem.in_param(1)?,
+                mem.in_param(2)?,
+                mem.out_param(3)?,
             )),
-            3 => Ok(Input(mem.out_param(1))),
-            4 => Ok(Output(mem.in_param(1))),
+            3 => Ok(Input(mem.out_param(1)?)),
+            4 => Ok(Output(mem.in_param(1)?)),
             #[allow(clippy::cast_sign_loss)]
-            op @ (5 | 6) => Ok(Jump(op == 5, mem.in_param(1), mem.in_param(2) as u64)),
+            op @ (5 | 6) => Ok(Jump(op == 5, mem.in_param(1)?, mem.in_param(2)? as u64)),
             op @ (7 | 8) => Ok(Compare(
                 if op == 7 { Lt } else { Eq },
-                mem.in_param(1),
-                mem.in_param(2),
-                mem.out_param(3),
+                mem.in_param(1)?,
+                mem.in_param(2)?,
+                mem.out_param(3)?,
             )),
-            9 => Ok(AdjustBase(mem.in_param(1))),
+            9 => Ok(AdjustBase(mem.in_param(1)?)),
             99 => Ok(Halt),
-            op => Err(format!("Invalid opcode {}", op)),
+            op => Err(Error::vm(mem.ptr, format!("Invalid opcode {}", op))),
         }
     }
 
@@ -103,39 +259,139 @@ impl Opcode {
             Halt => 1,
         }
     }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn operands(&self) -> Vec<i64> {
+        match *self {
+            Arith(_, v1, v2, out) | Compare(_, v1, v2, out) => vec![v1, v2, out as i64],
+            Input(out) => vec![out as i64],
+            Output(v) | AdjustBase(v) => vec![v],
+            Jump(_, v, ptr) => vec![v, ptr as i64],
+            Halt => vec![],
+        }
+    }
 }
 
-#[derive(PartialEq, Eq, Debug)]
+// An executed instruction along with its resolved operands: the values read
+// for input parameters and the address written for the output parameter.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Step {
+    pub line: Line,
+    pub operands: Vec<i64>,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.operands.is_empty() {
+            return write!(f, "{}", self.line);
+        }
+        let operands = self.operands.iter().map(i64::to_string).collect::<Vec<_>>();
+        write!(f, "{} ; {}", self.line, operands.join(", "))
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub struct Profile {
+    pub count: u64,
+    pub hits: HashMap<u64, u64>,
+    pub ops: HashMap<&'static str, u64>,
+    // The highest address an instruction was fetched from, read or written.
+    pub peak: u64,
+}
+
+impl Profile {
+    // Addresses by hit count, most executed first.
+    pub fn hottest(&self) -> Vec<(u64, u64)> {
+        let mut hits = self
+            .hits
+            .iter()
+            .map(|(&addr, &n)| (addr, n))
+            .collect::<Vec<_>>();
+        hits.sort_unstable_by_key(|&(addr, n)| (std::cmp::Reverse(n), addr));
+        hits
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "instructions {}", self.count)?;
+        writeln!(f, "peak address {}", self.peak)?;
+        writeln!(f, "\nopcode      count")?;
+        let mut ops = self.ops.iter().collect::<Vec<_>>();
+        ops.sort_unstable_by_key(|&(op, n)| (std::cmp::Reverse(n), op));
+        for (op, n) in ops {
+            writeln!(f, "{:<6} {:>10}", op, n)?;
+        }
+        writeln!(f, "\naddress      hits")?;
+        for (addr, n) in self.hottest() {
+            writeln!(f, "{:>7} {:>9}", addr, n)?;
+        }
+        Ok(())
+    }
+}
+
+// Memory below this address is stored contiguously, and anything above it in
+// a map, so that a stray write to a huge address doesn't allocate it all.
+const DENSE_LIMIT: u64 = 1 << 20;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub struct Memory {
-    mem: HashMap<u64, i64>,
+    cells: Vec<i64>,
+    sparse: HashMap<u64, i64>,
     ptr: u64,
     base: i64,
 }
 
 impl Memory {
+    #[allow(clippy::cast_possible_truncation)]
     fn set(&mut self, ptr: u64, val: i64) {
-        self.mem.insert(ptr, val);
+        if ptr >= DENSE_LIMIT {
+            self.sparse.insert(ptr, val);
+            return;
+        }
+        let idx = ptr as usize;
+        if idx >= self.cells.len() {
+            self.cells.resize(idx + 1, 0);
+        }
+        self.cells[idx] = val;
     }
 
     fn instr(&self) -> i64 {
         self[self.ptr]
     }
 
-    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
-    fn in_param(&self, param: u64) -> i64 {
-        match ParamMode::new(self.instr(), param as u32) {
+    #[allow(clippy::cast_possible_truncation)]
+    fn mode(&self, param: u64) -> Result<ParamMode, Error> {
+        ParamMode::new(self.instr(), param as u32).ok_or_else(|| {
+            Error::vm(
+                self.ptr,
+                format!("Invalid mode for parameter {} of {}", param, self.instr()),
+            )
+        })
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    fn in_param(&self, param: u64) -> Result<i64, Error> {
+        Ok(match self.mode(param)? {
             Immediate => self[self.ptr + param],
             Position => self[self[self.ptr + param] as u64],
             Relative => self[(self.base + self[self.ptr + param]) as u64],
-        }
+        })
     }
 
-    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
-    fn out_param(&self, param: u64) -> u64 {
-        match ParamMode::new(self.instr(), param as u32) {
-            Immediate => unreachable!(),
-            Position => self[self.ptr + param] as u64,
-            Relative => (self.base + self[self.ptr + param]) as u64,
+    #[allow(clippy::cast_sign_loss)]
+    fn out_param(&self, param: u64) -> Result<u64, Error> {
+        match self.mode(param)? {
+            Immediate => Err(Error::vm(
+                self.ptr,
+                format!(
+                    "Immediate mode for output parameter {} of {}",
+                    param,
+                    self.instr()
+                ),
+            )),
+            Position => Ok(self[self.ptr + param] as u64),
+            Relative => Ok((self.base + self[self.ptr + param]) as u64),
         }
     }
 }
@@ -143,11 +399,8 @@ impl Memory {
 impl From<Vec<i64>> for Memory {
     fn from(code: Vec<i64>) -> Self {
         Self {
-            mem: code
-                .iter()
-                .enumerate()
-                .map(|(idx, v)| (idx as u64, *v))
-                .collect(),
+            cells: code,
+            sparse: HashMap::new(),
             ptr: 0,
             base: 0,
         }
@@ -157,8 +410,13 @@ impl From<Vec<i64>> for Memory {
 impl Index<u64> for Memory {
     type Output = i64;
 
+    #[allow(clippy::cast_possible_truncation)]
     fn index(&self, ptr: u64) -> &Self::Output {
-        self.mem.get(&ptr).unwrap_or(&0)
+        if ptr < DENSE_LIMIT {
+            self.cells.get(ptr as usize).unwrap_or(&0)
+        } else {
+            self.sparse.get(&ptr).unwrap_or(&0)
+        }
     }
 }
 
@@ -167,25 +425,32 @@ pub struct Intcode {
     pub code: Vec<i64>,
 }
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub struct Runtime<I, O> {
     mem: Memory,
     stdin: I,
     stdout: O,
+    trace: Option<Vec<Step>>,
+    profile: Option<Profile>,
 }
 
 impl FromStr for Intcode {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(code: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            code: code
-                .trim()
-                .split(',')
-                .map(str::parse)
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|_| "Invalid input")?,
-        })
+        let line = code.trim();
+        let mut col = 1;
+        let code = line
+            .split(',')
+            .map(|val| {
+                let res = val
+                    .parse()
+                    .map_err(|_| Error::parse(1, col, line, format!("Invalid value {:?}", val)));
+                col += val.len() + 1;
+                res
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { code })
     }
 }
 
@@ -195,6 +460,104 @@ impl From<Vec<i64>> for Intcode {
     }
 }
 
+impl<I, O> Runtime<I, O> {
+    pub const fn ptr(&self) -> u64 {
+        self.mem.ptr
+    }
+
+    pub const fn base(&self) -> i64 {
+        self.mem.base
+    }
+
+    pub fn set(&mut self, ptr: u64, val: i64) {
+        self.mem.set(ptr, val);
+    }
+
+    pub const fn input(&self) -> &I {
+        &self.stdin
+    }
+
+    pub const fn input_mut(&mut self) -> &mut I {
+        &mut self.stdin
+    }
+
+    pub const fn output(&self) -> &O {
+        &self.stdout
+    }
+
+    pub const fn output_mut(&mut self) -> &mut O {
+        &mut self.stdout
+    }
+
+    // Record every instruction executed from now on, see `trace`.
+    #[must_use]
+    pub fn traced(mut self) -> Self {
+        self.trace = Some(vec![]);
+        self
+    }
+
+    // Count executed instructions from now on, see `profile`.
+    #[must_use]
+    pub fn profiled(mut self) -> Self {
+        self.profile = Some(Profile::default());
+        self
+    }
+
+    pub fn trace(&self) -> Option<&[Step]> {
+        self.trace.as_deref()
+    }
+
+    pub const fn profile(&self) -> Option<&Profile> {
+        self.profile.as_ref()
+    }
+
+    // Record the instruction about to run, once it is sure to run.
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    fn record(&mut self, op: &Opcode) {
+        let mem = &self.mem;
+        let ptr = mem.ptr;
+        if let Some(profile) = &mut self.profile {
+            let instr = mem.instr();
+            let &(_, name, nparams, _) = OPCODES
+                .iter()
+                .find(|(code, ..)| *code == instr % 100)
+                .unwrap();
+            profile.count += 1;
+            *profile.hits.entry(ptr).or_default() += 1;
+            *profile.ops.entry(name).or_default() += 1;
+            let peak = (1..=u64::from(nparams))
+                .filter_map(|param| match ParamMode::new(instr, param as u32)? {
+                    Immediate => None,
+                    Position => Some(mem[ptr + param] as u64),
+                    Relative => Some((mem.base + mem[ptr + param]) as u64),
+                })
+                .fold(ptr + op.size() - 1, u64::max);
+            profile.peak = profile.peak.max(peak);
+        }
+        if self.trace.is_some() {
+            let line = self.current();
+            if let Some(trace) = &mut self.trace {
+                trace.push(Step {
+                    line,
+                    operands: op.operands(),
+                });
+            }
+        }
+    }
+
+    // The instruction that will run next.
+    pub fn current(&self) -> Line {
+        let addr = self.mem.ptr;
+        match decode(|ptr| Some(self.mem[ptr]), addr) {
+            Some((op, params)) => Line::Instr { addr, op, params },
+            None => Line::Data {
+                addr,
+                vals: vec![self.mem[addr]],
+            },
+        }
+    }
+}
+
 impl<I, O> Index<u64> for Runtime<I, O> {
     type Output = i64;
 
@@ -203,66 +566,414 @@ impl<I, O> Index<u64> for Runtime<I, O> {
     }
 }
 
+// A piece of assembly source and its column, for error reporting.
+type Spanned<'a> = (&'a str, usize);
+
+// One instruction or `.data` directive of assembly source.
+struct Stmt<'a> {
+    lineno: usize,
+    line: &'a str,
+    op: Spanned<'a>,
+    args: Vec<Spanned<'a>>,
+}
+
+impl Stmt<'_> {
+    fn err(&self, col: usize, msg: impl fmt::Display) -> Error {
+        Error::parse(self.lineno, col, self.line, msg)
+    }
+
+    fn size(&self) -> Result<usize, Error> {
+        let (op, col) = self.op;
+        if op == ".data" {
+            return Ok(self.args.len());
+        }
+        let &(_, _, nparams, _) = OPCODES
+            .iter()
+            .find(|(_, name, ..)| *name == op)
+            .ok_or_else(|| self.err(col, format!("Unknown mnemonic {}", op)))?;
+        if self.args.len() != nparams as usize {
+            return Err(self.err(
+                col,
+                format!(
+                    "{} takes {} parameter(s), got {}",
+                    op,
+                    nparams,
+                    self.args.len()
+                ),
+            ));
+        }
+        Ok(self.args.len() + 1)
+    }
+
+    fn value(&self, (arg, col): Spanned<'_>, labels: &HashMap<&str, i64>) -> Result<i64, Error> {
+        if let Ok(val) = arg.parse() {
+            return Ok(val);
+        }
+        let (label, offset) = match arg.rfind(['+', '-']) {
+            Some(idx) if idx > 0 => (
+                arg[..idx].trim_end(),
+                arg[idx..]
+                    .replace(' ', "")
+                    .parse::<i64>()
+                    .map_err(|_| self.err(col, format!("Invalid value {}", arg)))?,
+            ),
+            _ => (arg, 0),
+        };
+        labels
+            .get(label)
+            .map(|addr| addr + offset)
+            .ok_or_else(|| self.err(col, format!("Unknown label {}", label)))
+    }
+
+    fn param(&self, (arg, col): Spanned<'_>, labels: &HashMap<&str, i64>) -> Result<Param, Error> {
+        let (mode, val) = if let Some(val) = arg.strip_prefix('#') {
+            (Immediate, self.value((val.trim(), col + 1), labels)?)
+        } else if let Some(val) = arg.strip_prefix('[').and_then(|val| val.strip_suffix(']')) {
+            (Position, self.value((val.trim(), col + 1), labels)?)
+        } else if let Some(val) = arg.strip_prefix("rb") {
+            let val = val.trim_start();
+            let (sign, val) = match val.chars().next() {
+                Some('+') => (1, &val[1..]),
+                Some('-') => (-1, &val[1..]),
+                _ => return Err(self.err(col, format!("Invalid relative parameter {}", arg))),
+            };
+            (Relative, sign * self.value((val.trim(), col + 3), labels)?)
+        } else {
+            return Err(self.err(col, format!("Missing parameter mode in {}", arg)));
+        };
+        Ok(Param { mode, val })
+    }
+
+    fn encode(&self, labels: &HashMap<&str, i64>, code: &mut Vec<i64>) -> Result<(), Error> {
+        let (op, _) = self.op;
+        if op == ".data" {
+            for arg in &self.args {
+                code.push(self.value(*arg, labels)?);
+            }
+            return Ok(());
+        }
+        let &(opcode, _, _, out) = OPCODES.iter().find(|(_, name, ..)| *name == op).unwrap();
+        let params = self
+            .args
+            .iter()
+            .map(|arg| self.param(*arg, labels))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut instr = opcode;
+        for (idx, param) in (1..).zip(&params) {
+            if param.mode == Immediate && out == Some(idx) {
+                return Err(self.err(
+                    self.args[idx as usize - 1].1,
+                    format!("Parameter {} of {} cannot be immediate", idx, op),
+                ));
+            }
+            instr += 10_i64.pow(idx + 1)
+                * match param.mode {
+                    Position => 0,
+                    Immediate => 1,
+                    Relative => 2,
+                };
+        }
+        code.push(instr);
+        code.extend(params.iter().map(|param| param.val));
+        Ok(())
+    }
+}
+
+// Split a line of assembly into its labels and statement. Labels are either
+// names or, as printed by `disassemble`, the address of the line.
+fn split_line(line: &str) -> (Vec<Spanned<'_>>, Option<Spanned<'_>>, Vec<Spanned<'_>>) {
+    let text = line.split(';').next().unwrap_or("");
+    let col = |s: &str| s.as_ptr() as usize - line.as_ptr() as usize + 1;
+    let mut labels = vec![];
+    let mut rest = text.trim_start();
+    while let Some((label, after)) = rest.split_once(':') {
+        let label = label.trim_end();
+        if label.is_empty() || !label.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            break;
+        }
+        labels.push((label, col(label)));
+        rest = after.trim_start();
+    }
+    let rest = rest.trim_end();
+    if rest.is_empty() {
+        return (labels, None, vec![]);
+    }
+    let (op, args) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    let args = if args.trim().is_empty() {
+        vec![]
+    } else {
+        args.split(',')
+            .map(|arg| {
+                let arg = arg.trim();
+                (arg, col(arg))
+            })
+            .collect()
+    };
+    (labels, Some((op, col(op))), args)
+}
+
 impl Intcode {
-    pub fn exec(&self) -> Runtime<io::Empty, io::Sink> {
+    // Assemble a program written the way `disassemble` prints one. Labels
+    // (`name:`) can be used anywhere a number can, optionally with an offset
+    // (`name+1`), and comments start with `;`.
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn assemble(src: &str) -> Result<Self, Error> {
+        let mut labels = HashMap::new();
+        let mut stmts = vec![];
+        let mut addr = 0;
+        for (idx, line) in src.lines().enumerate() {
+            let (names, op, args) = split_line(line);
+            for (name, col) in names {
+                let err = |msg| Error::parse(idx + 1, col, line, msg);
+                if name.starts_with(|c: char| c.is_ascii_digit()) {
+                    if name.parse::<usize>().ok() != Some(addr) {
+                        return Err(err(format!("Expected address {}", addr)));
+                    }
+                    continue;
+                }
+                match labels.entry(name) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(addr as i64);
+                    }
+                    Entry::Occupied(_) => return Err(err(format!("Duplicate label {}", name))),
+                }
+            }
+            if let Some(op) = op {
+                let stmt = Stmt {
+                    lineno: idx + 1,
+                    line,
+                    op,
+                    args,
+                };
+                addr += stmt.size()?;
+                stmts.push(stmt);
+            }
+        }
+
+        let mut code = Vec::with_capacity(addr);
+        for stmt in &stmts {
+            stmt.encode(&labels, &mut code)?;
+        }
+        Ok(Self { code })
+    }
+
+    pub fn disassemble(&self) -> Vec<Line> {
+        let mut lines = vec![];
+        let mut addr = 0;
+        while addr < self.code.len() {
+            let fetch = |addr: u64| self.code.get(usize::try_from(addr).ok()?).copied();
+            if let Some((op, params)) = decode(fetch, addr as u64) {
+                let size = params.len() + 1;
+                lines.push(Line::Instr {
+                    addr: addr as u64,
+                    op,
+                    params,
+                });
+                addr += size;
+                continue;
+            }
+            match lines.last_mut() {
+                Some(Line::Data { vals, .. }) if vals.len() < DATA_WIDTH => {
+                    vals.push(self.code[addr]);
+                }
+                _ => lines.push(Line::Data {
+                    addr: addr as u64,
+                    vals: vec![self.code[addr]],
+                }),
+            }
+            addr += 1;
+        }
+        lines
+    }
+
+    pub fn exec(&self) -> Runtime<VecDeque<i64>, io::Sink> {
         Runtime {
             mem: self.code.clone().into(),
-            stdin: io::empty(),
+            stdin: VecDeque::new(),
             stdout: io::sink(),
+            trace: None,
+            profile: None,
         }
     }
 }
 
-impl<I: io::Read, O: io::Write> Runtime<I, O> {
-    pub fn read_from<I2: io::Read>(self, stdin: I2) -> Runtime<I2, O> {
+impl<I: InputProvider, O: OutputSink> Runtime<I, O> {
+    pub fn read_from<I2: InputProvider>(self, stdin: I2) -> Runtime<I2, O> {
         Runtime {
             mem: self.mem,
             stdin,
             stdout: self.stdout,
+            trace: self.trace,
+            profile: self.profile,
         }
     }
 
-    pub fn write_to<O2: io::Write>(self, stdout: O2) -> Runtime<I, O2> {
+    pub fn write_to<O2: OutputSink>(self, stdout: O2) -> Runtime<I, O2> {
         Runtime {
             mem: self.mem,
             stdin: self.stdin,
             stdout,
+            trace: self.trace,
+            profile: self.profile,
         }
     }
 
-    pub fn read_vec(self, stdin: &[i64]) -> Runtime<io::Cursor<Vec<u8>>, O> {
-        self.read_from(io::Cursor::new(ints_to_bytes(stdin)))
+    pub fn read_vec(self, stdin: &[i64]) -> Runtime<VecDeque<i64>, O> {
+        self.read_from(stdin.iter().copied().collect())
     }
 
-    pub fn run(&mut self) -> Result<Vec<i64>, String> {
+    pub fn run(&mut self) -> Result<Vec<i64>, Error> {
         self.collect::<Result<Vec<_>, _>>()
             .map(|outs| outs.iter().copied().flatten().collect())
     }
 
-    pub fn run_with(&mut self, vals: &[(u64, i64)]) -> Result<Vec<i64>, String> {
+    pub fn run_with(&mut self, vals: &[(u64, i64)]) -> Result<Vec<i64>, Error> {
         for (idx, val) in vals {
             self.mem.set(*idx, *val);
         }
         self.run()
     }
 
-    pub fn run_to_out(&mut self) -> Result<Option<i64>, String> {
+    pub fn run_to_out(&mut self) -> Result<Option<i64>, Error> {
         self.find(|res| res.is_err() || res.as_ref().unwrap().is_some())
             .unwrap_or(Ok(None))
     }
 }
 
-impl<I: io::Read + io::Write + io::Seek, O: io::Write> Runtime<I, O> {
+// A paused runtime: its memory, registers and the input it has not read yet.
+// Snapshots print as, and parse from, one `name values` line per field.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Snapshot {
+    mem: Memory,
+    input: Vec<i64>,
+}
+
+impl Snapshot {
+    // Start a new runtime from the snapshot.
+    pub fn resume(&self) -> Runtime<VecDeque<i64>, io::Sink> {
+        Runtime {
+            mem: self.mem.clone(),
+            stdin: self.input.iter().copied().collect(),
+            stdout: io::sink(),
+            trace: None,
+            profile: None,
+        }
+    }
+}
+
+fn join<T: ToString>(vals: impl Iterator<Item = T>) -> String {
+    vals.map(|val| val.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sparse = self.mem.sparse.iter().collect::<Vec<_>>();
+        sparse.sort_unstable();
+        writeln!(f, "ip {}", self.mem.ptr)?;
+        writeln!(f, "rb {}", self.mem.base)?;
+        writeln!(f, "input {}", join(self.input.iter()))?;
+        writeln!(f, "mem {}", join(self.mem.cells.iter()))?;
+        writeln!(
+            f,
+            "sparse {}",
+            join(sparse.iter().map(|(ptr, val)| format!("{}:{}", ptr, val)))
+        )
+    }
+}
+
+impl FromStr for Snapshot {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn num<T: FromStr>(val: &str) -> Result<T, String> {
+            val.parse().map_err(|_| format!("Invalid value {:?}", val))
+        }
+
+        let mut mem = Memory::from(vec![]);
+        let mut input = vec![];
+        for (idx, line) in s.lines().enumerate() {
+            let (name, vals) = line.split_once(' ').unwrap_or((line, ""));
+            let err = |msg| Error::parse(idx + 1, name.len() + 2, line, msg);
+            let mut vals = vals.split(',').filter(|val| !val.is_empty());
+            match name {
+                "ip" => mem.ptr = num(vals.next().unwrap_or_default()).map_err(err)?,
+                "rb" => mem.base = num(vals.next().unwrap_or_default()).map_err(err)?,
+                "input" => input = vals.map(num).collect::<Result<_, _>>().map_err(err)?,
+                "mem" => mem.cells = vals.map(num).collect::<Result<_, _>>().map_err(err)?,
+                "sparse" => {
+                    for cell in vals {
+                        let err = |msg| Error::parse(idx + 1, column(line, cell), line, msg);
+                        let (ptr, val) = cell
+                            .split_once(':')
+                            .ok_or_else(|| err(format!("Invalid cell {:?}", cell)))?;
+                        let ptr = num(ptr).map_err(err)?;
+                        if ptr < DENSE_LIMIT {
+                            return Err(err(format!(
+                                "Sparse address {} is below {}",
+                                ptr, DENSE_LIMIT
+                            )));
+                        }
+                        mem.sparse.insert(ptr, num(val).map_err(err)?);
+                    }
+                }
+                _ => {
+                    return Err(Error::parse(
+                        idx + 1,
+                        1,
+                        line,
+                        format!("Unknown field {:?}", name),
+                    ))
+                }
+            }
+        }
+        Ok(Self { mem, input })
+    }
+}
+
+impl<O> Runtime<VecDeque<i64>, O> {
     pub fn read_next(&mut self, stdin: &[i64]) {
-        let pos = self.stdin.seek(io::SeekFrom::Current(0)).unwrap();
-        self.stdin.seek(io::SeekFrom::End(0)).unwrap();
-        self.stdin.write_all(&ints_to_bytes(stdin)).unwrap();
-        self.stdin.seek(io::SeekFrom::Start(pos)).unwrap();
+        self.stdin.extend(stdin);
+    }
+
+    // Input queued but not read yet.
+    pub const fn pending(&self) -> &VecDeque<i64> {
+        &self.stdin
+    }
+
+    // Whether the next instruction is an `in` with no input queued for it.
+    pub fn awaiting_input(&self) -> bool {
+        matches!(Opcode::new(&self.mem), Ok(Input(_))) && self.stdin.is_empty()
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            mem: self.mem.clone(),
+            input: self.stdin.iter().copied().collect(),
+        }
+    }
+
+    // Go back to `snapshot`, replacing any pending input with the snapshot's.
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.mem = snapshot.mem.clone();
+        self.stdin = snapshot.input.iter().copied().collect();
+    }
+
+    // An independent copy of the runtime that writes its output nowhere.
+    pub fn fork(&self) -> Runtime<VecDeque<i64>, io::Sink> {
+        Runtime {
+            mem: self.mem.clone(),
+            stdin: self.stdin.clone(),
+            stdout: io::sink(),
+            trace: None,
+            profile: None,
+        }
     }
 }
 
-impl<I: io::Read, O: io::Write> Iterator for Runtime<I, O> {
-    type Item = Result<Option<i64>, String>;
+impl<I: InputProvider, O: OutputSink> Iterator for Runtime<I, O> {
+    type Item = Result<Option<i64>, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let op = Opcode::new(&self.mem);
@@ -270,32 +981,36 @@ impl<I: io::Read, O: io::Write> Iterator for Runtime<I, O> {
             return Some(Err(err));
         }
         let op = op.unwrap();
-        let mut jumped = false;
+        // Do any I/O before recording the instruction: a failed read or write
+        // leaves it to be retried, and must not count it twice.
         let mut out = None;
+        let read = match op {
+            Input(_) => match self.stdin.next_input() {
+                Some(val) => Some(val),
+                None => return Some(Err(Error::vm(self.mem.ptr, "Invalid read"))),
+            },
+            Output(val) => {
+                if !self.stdout.push_output(val) {
+                    return Some(Err(Error::vm(self.mem.ptr, "Invalid write")));
+                }
+                out = Some(val);
+                None
+            }
+            _ => None,
+        };
+        if self.trace.is_some() || self.profile.is_some() {
+            self.record(&op);
+        }
+        let mut jumped = false;
 
         match op {
             Arith(binop, v1, v2, out) => self.mem.set(out, binop.eval(v1, v2)),
             Input(out) => {
-                let mut buf = [0; 8];
-                if let Err(err) = self
-                    .stdin
-                    .read_exact(&mut buf)
-                    .map_err(|_| "Invalid read".into())
-                {
-                    return Some(Err(err));
-                }
-                self.mem.set(out, i64::from_le_bytes(buf));
-            }
-            Output(val) => {
-                out = Some(val);
-                if let Err(err) = self
-                    .stdout
-                    .write(&val.to_le_bytes())
-                    .map_err(|_| "Invalid write".into())
-                {
-                    return Some(Err(err));
+                if let Some(val) = read {
+                    self.mem.set(out, val);
                 }
             }
+            Output(_) | Halt => {}
             Jump(b, v, ptr) => {
                 if b ^ (v == 0) {
                     self.mem.ptr = ptr;
@@ -304,7 +1019,6 @@ impl<I: io::Read, O: io::Write> Iterator for Runtime<I, O> {
             }
             Compare(cmp, v1, v2, out) => self.mem.set(out, if cmp.eval(v1, v2) { 1 } else { 0 }),
             AdjustBase(v) => self.mem.base += v,
-            Halt => {}
         };
 
         if !jumped {
@@ -326,18 +1040,18 @@ mod tests {
     fn test_empty_io() {
         let mut p = Intcode::from(vec![1, 0, 0, 0, 99]).exec();
         assert!(p.run().is_ok());
-        assert_eq!(p.mem.mem, Memory::from(vec![2, 0, 0, 0, 99]).mem);
+        assert_eq!(p.mem.cells, Memory::from(vec![2, 0, 0, 0, 99]).cells);
         let mut p = Intcode::from(vec![2, 3, 0, 3, 99]).exec();
         assert!(p.run().is_ok());
-        assert_eq!(p.mem.mem, Memory::from(vec![2, 3, 0, 6, 99]).mem);
+        assert_eq!(p.mem.cells, Memory::from(vec![2, 3, 0, 6, 99]).cells);
         let mut p = Intcode::from(vec![2, 4, 4, 5, 99, 0]).exec();
         assert!(p.run().is_ok());
-        assert_eq!(p.mem.mem, Memory::from(vec![2, 4, 4, 5, 99, 9801]).mem);
+        assert_eq!(p.mem.cells, Memory::from(vec![2, 4, 4, 5, 99, 9801]).cells);
         let mut p = Intcode::from(vec![1, 1, 1, 4, 99, 5, 6, 0, 99]).exec();
         assert!(p.run().is_ok());
         assert_eq!(
-            p.mem.mem,
-            Memory::from(vec![30, 1, 1, 4, 2, 5, 6, 0, 99]).mem
+            p.mem.cells,
+            Memory::from(vec![30, 1, 1, 4, 2, 5, 6, 0, 99]).cells
         );
     }
 
@@ -345,7 +1059,7 @@ mod tests {
     fn test_param_mode() {
         let mut p = Intcode::from(vec![1101, 100, -1, 4, 0]).exec();
         assert!(p.run().is_ok());
-        assert_eq!(p.mem.mem, Memory::from(vec![1101, 100, -1, 4, 99]).mem);
+        assert_eq!(p.mem.cells, Memory::from(vec![1101, 100, -1, 4, 99]).cells);
     }
 
     #[test]
@@ -357,6 +1071,31 @@ mod tests {
         assert_eq!(p.run_to_out(), Ok(Some(1)));
     }
 
+    #[test]
+    fn test_io() {
+        let mut p = countdown().exec();
+        assert_eq!(p.run(), Err(Error::vm(0, "Invalid read")));
+        p.read_next(&[2]);
+        assert_eq!(p.run(), Ok(vec![2, 1]));
+
+        let mut input = vec![3].into_iter();
+        let mut p = countdown()
+            .exec()
+            .read_from(move || input.next())
+            .write_to(VecDeque::new());
+        assert_eq!(p.run(), Ok(vec![3, 2, 1]));
+        assert_eq!(p.stdout, [3, 2, 1]);
+
+        let (tx_in, rx_in) = mpsc::channel();
+        let (tx_out, rx_out) = mpsc::channel();
+        let prog = countdown();
+        let handle =
+            std::thread::spawn(move || prog.exec().read_from(rx_in).write_to(tx_out).run());
+        tx_in.send(2).unwrap();
+        assert_eq!(rx_out.iter().collect::<Vec<_>>(), [2, 1]);
+        assert_eq!(handle.join().unwrap(), Ok(vec![2, 1]));
+    }
+
     #[test]
     fn test_eq() {
         let eq1 = Intcode::from(vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8]);
@@ -416,6 +1155,252 @@ mod tests {
         assert_eq!(p.run(), Ok(vec![1001]));
     }
 
+    #[test]
+    fn test_errors() {
+        let mut p = Intcode::from(vec![1101, 1, 1, 5, 42]).exec();
+        assert_eq!(p.run(), Err(Error::vm(4, "Invalid opcode 42")));
+        let mut p = Intcode::from(vec![1103, 0, 99]).exec().read_vec(&[1]);
+        assert_eq!(
+            p.run(),
+            Err(Error::vm(
+                0,
+                "Immediate mode for output parameter 1 of 1103"
+            ))
+        );
+        let mut p = Intcode::from(vec![301, 0, 0, 0, 99]).exec().traced();
+        assert_eq!(
+            p.run(),
+            Err(Error::vm(0, "Invalid mode for parameter 1 of 301"))
+        );
+        assert_eq!(
+            "1,2,x".parse::<Intcode>(),
+            Err(Error::parse(1, 5, "1,2,x", "Invalid value \"x\""))
+        );
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let lines = |code: Vec<i64>| {
+            Intcode::from(code)
+                .disassemble()
+                .iter()
+                .map(Line::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            lines(vec![1101, 100, -1, 4, 0]),
+            ["   0: add #100, #-1, [4]", "   4: .data 0"]
+        );
+        assert_eq!(
+            lines(vec![109, 1, 204, -1, 1006, 101, 0, 99]),
+            [
+                "   0: arb #1",
+                "   2: out rb-1",
+                "   4: jz [101], #0",
+                "   7: hlt"
+            ]
+        );
+        assert_eq!(
+            lines(vec![
+                3, 9, 21101, 1, 2, 3, 99, -5, 10_001, 0, 0, 0, 0, 0, 0, 0, 0
+            ]),
+            [
+                "   0: in [9]",
+                "   2: add #1, #2, rb+3",
+                "   6: hlt",
+                "   7: .data -5, 10001, 0, 0, 0, 0, 0, 0",
+                "  15: .data 0, 0",
+            ]
+        );
+        assert_eq!(lines(vec![1103, 0]), ["   0: .data 1103, 0"]);
+    }
+
+    #[test]
+    fn test_assemble() {
+        let src = "
+            ; Count down from the input, printing each value.
+                    in [n]
+            loop:   out [n]
+                    add [n], #-1, [n]
+                    jnz [n], #loop
+                    arb #buf+1
+                    out rb-1
+                    hlt
+            n:      .data 0
+            buf:    .data 42, loop
+        ";
+        let prog = Intcode::assemble(src).unwrap();
+        assert_eq!(
+            prog.code,
+            [3, 16, 4, 16, 1001, 16, -1, 16, 1005, 16, 2, 109, 18, 204, -1, 99, 0, 42, 2]
+        );
+        let mut p = prog.exec().read_vec(&[3]).write_to(vec![]);
+        assert_eq!(p.run(), Ok(vec![3, 2, 1, 42]));
+        assert_eq!(
+            Intcode::assemble("in [0]\nout [0]\nhlt"),
+            Ok(Intcode::from(vec![3, 0, 4, 0, 99]))
+        );
+        assert_eq!(
+            countdown().code,
+            [3, 12, 4, 12, 1001, 12, -1, 12, 1005, 12, 2, 99, 0]
+        );
+
+        let err = |src| Intcode::assemble(src).unwrap_err();
+        assert_eq!(
+            err("nop"),
+            Error::parse(1, 1, "nop", "Unknown mnemonic nop")
+        );
+        assert_eq!(
+            err("hlt\nout #1, #2"),
+            Error::parse(2, 1, "out #1, #2", "out takes 1 parameter(s), got 2")
+        );
+        assert_eq!(
+            err("in #1"),
+            Error::parse(1, 4, "in #1", "Parameter 1 of in cannot be immediate")
+        );
+        assert_eq!(
+            err("out [x]"),
+            Error::parse(1, 6, "out [x]", "Unknown label x")
+        );
+        assert_eq!(
+            err("x: hlt\nx: hlt"),
+            Error::parse(2, 1, "x: hlt", "Duplicate label x")
+        );
+        assert_eq!(
+            err("out 1"),
+            Error::parse(1, 5, "out 1", "Missing parameter mode in 1")
+        );
+        assert_eq!(
+            err("1: hlt"),
+            Error::parse(1, 1, "1: hlt", "Expected address 0")
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        use crate::y19::{d02, d05, d09};
+
+        for input in [d02::INPUT, d05::INPUT, d09::INPUT] {
+            let prog = input.parse::<Intcode>().unwrap();
+            let src = prog
+                .disassemble()
+                .iter()
+                .map(Line::to_string)
+                .collect::<Vec<_>>()
+                .join("\n");
+            assert_eq!(Intcode::assemble(&src), Ok(prog));
+        }
+    }
+
+    fn countdown() -> Intcode {
+        Intcode::assemble(include_str!("../../tests/fixtures/countdown.s")).unwrap()
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut p = countdown().exec().read_vec(&[2]).traced();
+        assert_eq!(p.run(), Ok(vec![2, 1]));
+        let trace = p.trace().unwrap();
+        assert_eq!(trace.len(), 8);
+        assert_eq!(
+            trace[..4].iter().map(Step::to_string).collect::<Vec<_>>(),
+            [
+                "   0: in [12] ; 12",
+                "   2: out [12] ; 2",
+                "   4: add [12], #-1, [12] ; 2, -1, 12",
+                "   8: jnz [12], #2 ; 1, 2",
+            ]
+        );
+        assert_eq!(trace[7].to_string(), "  11: hlt");
+        assert_eq!(p.profile(), None);
+    }
+
+    #[test]
+    fn test_profile() {
+        let mut p = countdown().exec().read_vec(&[2]).profiled();
+        assert_eq!(p.run(), Ok(vec![2, 1]));
+        let profile = p.profile().unwrap();
+        assert_eq!(profile.count, 8);
+        assert_eq!(profile.peak, 12);
+        assert_eq!(profile.ops["add"], 2);
+        assert_eq!(profile.ops["in"], 1);
+        assert_eq!(profile.hottest(), [(2, 2), (4, 2), (8, 2), (0, 1), (11, 1)]);
+        assert_eq!(
+            profile.to_string().lines().take(6).collect::<Vec<_>>(),
+            [
+                "instructions 8",
+                "peak address 12",
+                "",
+                "opcode      count",
+                "add             2",
+                "jnz             2",
+            ]
+        );
+        assert_eq!(p.trace(), None);
+    }
+
+    #[test]
+    fn test_profile_retry() {
+        let mut p = countdown().exec().traced().profiled();
+        assert_eq!(p.run(), Err(Error::vm(0, "Invalid read")));
+        assert_eq!(p.run(), Err(Error::vm(0, "Invalid read")));
+        p.read_next(&[2]);
+        assert_eq!(p.run(), Ok(vec![2, 1]));
+        assert_eq!(p.trace().unwrap().len(), 8);
+        let profile = p.profile().unwrap();
+        assert_eq!(profile.count, 8);
+        assert_eq!(profile.ops["in"], 1);
+        assert_eq!(profile.hits[&0], 1);
+    }
+
+    #[test]
+    fn test_awaiting_input() {
+        let mut p = countdown().exec();
+        assert!(p.awaiting_input());
+        p.read_next(&[1]);
+        assert!(!p.awaiting_input());
+        assert_eq!(p.run_to_out(), Ok(Some(1)));
+        assert!(!p.awaiting_input());
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut p = countdown().exec().read_vec(&[3, 7]);
+        assert_eq!(p.run_to_out(), Ok(Some(3)));
+        p.set(1 << 40, 5);
+        let snap = p.snapshot();
+        assert_eq!(
+            snap.to_string(),
+            "ip 4\nrb 0\ninput 7\nmem 3,12,4,12,1001,12,-1,12,1005,12,2,99,3\nsparse 1099511627776:5\n"
+        );
+        assert_eq!(snap.to_string().parse::<Snapshot>(), Ok(snap.clone()));
+        assert_eq!(
+            "ip 4\nrb x".parse::<Snapshot>(),
+            Err(Error::parse(2, 4, "rb x", "Invalid value \"x\""))
+        );
+        assert_eq!(
+            "mem 1,2\nsparse 1048576:1,12:5".parse::<Snapshot>(),
+            Err(Error::parse(
+                2,
+                18,
+                "sparse 1048576:1,12:5",
+                "Sparse address 12 is below 1048576"
+            ))
+        );
+
+        assert_eq!(p.run(), Ok(vec![2, 1]));
+        p.restore(&snap);
+        assert_eq!(p.run(), Ok(vec![2, 1]));
+        assert_eq!(snap.resume().run(), Ok(vec![2, 1]));
+
+        p.restore(&snap);
+        let mut fork = p.fork();
+        fork.set(12, 1);
+        assert_eq!(fork.run(), Ok(vec![]));
+        assert_eq!(p.run(), Ok(vec![2, 1]));
+        assert_eq!(fork.snapshot().input, [7]);
+    }
+
     #[test]
     fn test_relative() {
         let code = vec![
@@ -425,6 +1410,23 @@ mod tests {
         assert_eq!(p.run(), Ok(code));
     }
 
+    #[test]
+    fn test_memory() {
+        let src = "
+            add #1, #2, [2000]
+            add [2000], #3, [1000000000000]
+            out [2000]
+            out [1000000000000]
+            out [999999]
+            hlt
+        ";
+        let mut p = Intcode::assemble(src).unwrap().exec().write_to(vec![]);
+        assert_eq!(p.run(), Ok(vec![3, 6, 0]));
+        assert_eq!(p.mem.cells.len(), 2001);
+        assert_eq!(p.mem.sparse.len(), 1);
+        assert_eq!(p[1_000_000_000_000], 6);
+    }
+
     #[test]
     fn test_big_number() {
         let mut p = Intcode::from(vec![1102, 34_915_192, 34_915_192, 7, 4, 7, 99, 0])
@@ -0,0 +1,202 @@
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+
+use super::intcode::{InputProvider, Intcode, Runtime};
+use crate::error::Error;
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Packet {
+    pub dest: i64,
+    pub x: i64,
+    pub y: i64,
+}
+
+// What the network should do after consulting its `Nat`.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Action<T> {
+    Continue,
+    Send(Packet),
+    Stop(T),
+}
+
+// Handles packets sent to addresses outside the network, and decides what to
+// do once the network goes idle.
+pub trait Nat {
+    type Output;
+
+    fn receive(&mut self, packet: Packet) -> Action<Self::Output>;
+
+    fn idle(&mut self) -> Action<Self::Output> {
+        Action::Continue
+    }
+}
+
+// A machine's network interface: its queue of incoming packet values, which
+// reads as -1 when empty.
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct Nic {
+    queue: VecDeque<i64>,
+    starved: bool,
+}
+
+impl InputProvider for Nic {
+    fn next_input(&mut self) -> Option<i64> {
+        let val = self.queue.pop_front();
+        self.starved = val.is_none();
+        Some(val.unwrap_or(-1))
+    }
+}
+
+// Machines running the same program, each reading its address first and then
+// packets as `x, y` pairs, and writing packets as `dest, x, y` triples.
+//
+// Machines are run in address order, each until it reads from an empty
+// queue, so runs are deterministic. The network is idle once a whole round
+// passes without any packets being sent.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Network {
+    machines: Vec<Runtime<Nic, Vec<i64>>>,
+    halted: Vec<bool>,
+    steps: u64,
+}
+
+impl Network {
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn boot(prog: &Intcode, n: usize) -> Self {
+        let machines = (0..n)
+            .map(|addr| {
+                let nic = Nic {
+                    queue: VecDeque::from(vec![addr as i64]),
+                    starved: false,
+                };
+                prog.exec().read_from(nic).write_to(vec![])
+            })
+            .collect();
+        Self {
+            machines,
+            halted: vec![false; n],
+            steps: 0,
+        }
+    }
+
+    // Instructions executed across all machines so far.
+    pub const fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    // Queue a packet for its destination, handing it back if no machine has
+    // that address.
+    pub fn send(&mut self, packet: Packet) -> Option<Packet> {
+        let machine = usize::try_from(packet.dest)
+            .ok()
+            .and_then(|dest| self.machines.get_mut(dest));
+        match machine {
+            Some(machine) => {
+                machine.input_mut().queue.extend([packet.x, packet.y]);
+                None
+            }
+            None => Some(packet),
+        }
+    }
+
+    fn act<T>(&mut self, action: Action<T>) -> Result<Option<T>, Error> {
+        match action {
+            Action::Continue => Ok(None),
+            Action::Send(packet) => match self.send(packet) {
+                None => Ok(None),
+                Some(packet) => Err(Error::runtime(format!(
+                    "No machine has address {}",
+                    packet.dest
+                ))),
+            },
+            Action::Stop(res) => Ok(Some(res)),
+        }
+    }
+
+    // Run until `nat` stops the network, giving up after `budget` more
+    // instructions.
+    pub fn run<N: Nat>(&mut self, nat: &mut N, budget: u64) -> Result<N::Output, Error> {
+        let limit = self.steps + budget;
+        loop {
+            let mut sent = false;
+            for idx in 0..self.machines.len() {
+                if self.halted[idx] {
+                    continue;
+                }
+                self.machines[idx].input_mut().starved = false;
+                while !self.machines[idx].input().starved {
+                    if self.steps >= limit {
+                        return Err(Error::no_solution(format!(
+                            "Network still running after {} steps",
+                            budget
+                        )));
+                    }
+                    self.steps += 1;
+                    match self.machines[idx].next() {
+                        None => {
+                            self.halted[idx] = true;
+                            break;
+                        }
+                        Some(Err(err)) => return Err(err),
+                        Some(Ok(_)) if self.machines[idx].output().len() == 3 => {
+                            let out = self.machines[idx].output_mut();
+                            let packet = Packet {
+                                dest: out[0],
+                                x: out[1],
+                                y: out[2],
+                            };
+                            out.clear();
+                            sent = true;
+                            if let Some(packet) = self.send(packet) {
+                                if let Some(res) = self.act(nat.receive(packet))? {
+                                    return Ok(res);
+                                }
+                            }
+                        }
+                        Some(Ok(_)) => {}
+                    }
+                }
+            }
+            if self.halted.iter().all(|&halted| halted) {
+                return Err(Error::no_solution("Every machine has halted"));
+            }
+            if !sent {
+                if let Some(res) = self.act(nat.idle())? {
+                    return Ok(res);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Ignore;
+
+    impl Nat for Ignore {
+        type Output = ();
+
+        fn receive(&mut self, _: Packet) -> Action<()> {
+            Action::Continue
+        }
+    }
+
+    #[test]
+    fn test_budget() {
+        let prog = Intcode::assemble("loop: jz #0, #loop").unwrap();
+        let mut net = Network::boot(&prog, 2);
+        assert_eq!(
+            net.run(&mut Ignore, 100),
+            Err(Error::no_solution("Network still running after 100 steps"))
+        );
+        assert_eq!(net.steps(), 100);
+
+        let prog = Intcode::assemble("in [0]\nhlt").unwrap();
+        assert_eq!(
+            Network::boot(&prog, 2).run(&mut Ignore, 100),
+            Err(Error::no_solution("Every machine has halted"))
+        );
+    }
+}
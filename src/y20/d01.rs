@@ -1,3 +1,6 @@
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 fn find_sum(n: u32, tgt: u32, xs: &[u32]) -> Option<Vec<u32>> {
     if n == 1 {
         xs.contains(&tgt).then(|| vec![tgt])
@@ -16,28 +19,61 @@ fn solve(n: u32, xs: &[u32]) -> Option<u32> {
     find_sum(n, 2020, xs).map(|xs| xs.iter().product())
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d01.txt");
-    let xs: Vec<u32> = input.lines().map(|x| x.parse::<u32>().unwrap()).collect();
-    let out1 = solve(2, &xs).ok_or("No solution found")?;
-    let out2 = solve(3, &xs).ok_or("No solution found")?;
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d01.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "1721\n\
+            979\n\
+            366\n\
+            299\n\
+            675\n\
+            1456",
+    part1: Some("514579"),
+    part2: Some("241861950"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Report Repair";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input)
+    }
+
+    fn part1(xs: &Self::Input) -> Result<Answer, Error> {
+        solve(2, xs)
+            .map(Answer::from)
+            .ok_or_else(|| Error::no_solution("No solution found"))
+    }
+
+    fn part2(xs: &Self::Input) -> Result<Answer, Error> {
+        solve(3, xs)
+            .map(Answer::from)
+            .ok_or_else(|| Error::no_solution("No solution found"))
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        assert_eq!(solve(2, &[1721, 979, 366, 299, 675, 1456]), Some(514_579));
+        let xs = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(2, &xs), Some(514_579));
     }
 
     #[test]
     fn test02() {
-        assert_eq!(
-            solve(3, &[1721, 979, 366, 299, 675, 1456]),
-            Some(241_861_950)
-        );
+        let xs = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(3, &xs), Some(241_861_950));
     }
 }
@@ -1,13 +1,16 @@
 use std::str::FromStr;
 
-#[derive(PartialEq, Eq, Debug)]
+use crate::error::{parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
+#[derive(PartialEq, Eq, Debug, Clone)]
 enum Mode {
     MinMax,
     PosXor,
 }
 use Mode::*;
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 struct Policy {
     mode: Mode,
     letter: char,
@@ -19,13 +22,15 @@ impl FromStr for Policy {
     type Err = String;
 
     fn from_str(policy: &str) -> Result<Self, Self::Err> {
-        let fields: Vec<&str> = policy.split(' ').collect();
-        let range: Vec<&str> = fields[0].split('-').collect();
+        let (range, letter) = policy
+            .split_once(' ')
+            .ok_or("Expected a range and a letter")?;
+        let (n1, n2) = range.split_once('-').ok_or("Invalid range")?;
         Ok(Self {
             mode: MinMax,
-            letter: fields[1].parse().map_err(|_| "Invalid letter")?,
-            n1: range[0].parse().map_err(|_| "Invalid n1")?,
-            n2: range[1].parse().map_err(|_| "Invalid n2")?,
+            letter: letter.parse().map_err(|_| "Invalid letter")?,
+            n1: n1.parse().map_err(|_| "Invalid n1")?,
+            n2: n2.parse().map_err(|_| "Invalid n2")?,
         })
     }
 }
@@ -53,27 +58,58 @@ impl Policy {
     }
 }
 
-fn solve(xs: &[(Policy, &str)]) -> usize {
+fn solve<S: AsRef<str>>(xs: &[(Policy, S)]) -> usize {
     xs.iter()
-        .filter(|(policy, pass)| policy.is_valid(pass))
+        .filter(|(policy, pass)| policy.is_valid(pass.as_ref()))
         .count()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d02.txt");
-    let mut xs: Vec<(Policy, &str)> = input
-        .lines()
-        .map(|x| {
-            let x: Vec<_> = x.split(": ").collect();
-            Ok((x[0].parse()?, x[1]))
-        })
-        .collect::<Result<_, String>>()?;
-    let out1 = solve(&xs);
-    xs.iter_mut().for_each(|(policy, _)| policy.swap_mode());
-    let out2 = solve(&xs);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d02.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "1-3 a: abcde\n\
+            1-3 b: cdefg\n\
+            2-9 c: ccccccccc",
+    part1: Some("2"),
+    part2: Some("1"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Password Philosophy";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<(Policy, String)>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                let (policy, pass) = line.split_once(": ").ok_or_else(|| {
+                    Error::parse(idx + 1, 1, line, "Expected a policy and a password")
+                })?;
+                Ok((parse_field(idx + 1, line, policy)?, pass.into()))
+            })
+            .collect()
+    }
+
+    fn part1(xs: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(xs).into())
+    }
+
+    fn part2(xs: &Self::Input) -> Result<Answer, Error> {
+        let mut xs = xs.clone();
+        xs.iter_mut().for_each(|(policy, _)| policy.swap_mode());
+        Ok(solve(&xs).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,73 +150,14 @@ mod tests {
 
     #[test]
     fn test01() {
-        assert_eq!(
-            solve(&[
-                (
-                    Policy {
-                        mode: MinMax,
-                        letter: 'a',
-                        n1: 1,
-                        n2: 3
-                    },
-                    "abcde"
-                ),
-                (
-                    Policy {
-                        mode: MinMax,
-                        letter: 'b',
-                        n1: 1,
-                        n2: 3
-                    },
-                    "cdefg"
-                ),
-                (
-                    Policy {
-                        mode: MinMax,
-                        letter: 'c',
-                        n1: 2,
-                        n2: 9
-                    },
-                    "ccccccccc"
-                )
-            ]),
-            2
-        );
+        let xs = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(&xs), 2);
     }
 
     #[test]
     fn test02() {
-        assert_eq!(
-            solve(&[
-                (
-                    Policy {
-                        mode: PosXor,
-                        letter: 'a',
-                        n1: 1,
-                        n2: 3
-                    },
-                    "abcde"
-                ),
-                (
-                    Policy {
-                        mode: PosXor,
-                        letter: 'b',
-                        n1: 1,
-                        n2: 3
-                    },
-                    "cdefg"
-                ),
-                (
-                    Policy {
-                        mode: PosXor,
-                        letter: 'c',
-                        n1: 2,
-                        n2: 9
-                    },
-                    "ccccccccc"
-                )
-            ]),
-            1
-        );
+        let mut xs = Solver::parse(EXAMPLES[0].input).unwrap();
+        xs.iter_mut().for_each(|(policy, _)| policy.swap_mode());
+        assert_eq!(solve(&xs), 1);
     }
 }
@@ -1,6 +1,9 @@
 use std::ops::Index;
 use std::str::FromStr;
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 struct Slope {
     right: usize,
@@ -42,17 +45,19 @@ struct Grid {
 }
 
 impl FromStr for Grid {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(map: &str) -> Result<Self, Self::Err> {
         let map = map
             .lines()
-            .map(|row| {
-                row.chars()
-                    .map(|c| match c {
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| match c {
                         '.' => Ok(Open),
                         '#' => Ok(Tree),
-                        _ => Err("Invalid cell"),
+                        _ => Err(Error::parse(row + 1, col + 1, line, "Invalid cell")),
                     })
                     .collect()
             })
@@ -95,23 +100,59 @@ fn solve(grid: &Grid, slopes: &[Slope]) -> usize {
         .product()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d03.txt");
-    let grid = input.parse()?;
-    let out1 = solve(&grid, &[Slope::new(3, 1)]);
-    let out2 = solve(
-        &grid,
-        &[
-            Slope::new(1, 1),
-            Slope::new(3, 1),
-            Slope::new(5, 1),
-            Slope::new(7, 1),
-            Slope::new(1, 2),
-        ],
-    );
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d03.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "..##.......\n\
+            #...#...#..\n\
+            .#....#..#.\n\
+            ..#.#...#.#\n\
+            .#...##..#.\n\
+            ..#.##.....\n\
+            .#.#.#....#\n\
+            .#........#\n\
+            #.##...#...\n\
+            #...##....#\n\
+            .#..#...#.#",
+    part1: Some("7"),
+    part2: Some("336"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 3;
+    const TITLE: &'static str = "Toboggan Trajectory";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(grid, &[Slope::new(3, 1)]).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(
+            grid,
+            &[
+                Slope::new(1, 1),
+                Slope::new(3, 1),
+                Slope::new(5, 1),
+                Slope::new(7, 1),
+                Slope::new(1, 2),
+            ],
+        )
+        .into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,37 +184,13 @@ mod tests {
 
     #[test]
     fn test01() {
-        let grid = "..##.......\n\
-                    #...#...#..\n\
-                    .#....#..#.\n\
-                    ..#.#...#.#\n\
-                    .#...##..#.\n\
-                    ..#.##.....\n\
-                    .#.#.#....#\n\
-                    .#........#\n\
-                    #.##...#...\n\
-                    #...##....#\n\
-                    .#..#...#.#"
-            .parse()
-            .unwrap();
+        let grid = EXAMPLES[0].input.parse().unwrap();
         assert_eq!(count_trees(&grid, Slope::new(3, 1)), 7);
     }
 
     #[test]
     fn test02() {
-        let grid = "..##.......\n\
-                    #...#...#..\n\
-                    .#....#..#.\n\
-                    ..#.#...#.#\n\
-                    .#...##..#.\n\
-                    ..#.##.....\n\
-                    .#.#.#....#\n\
-                    .#........#\n\
-                    #.##...#...\n\
-                    #...##....#\n\
-                    .#..#...#.#"
-            .parse()
-            .unwrap();
+        let grid = EXAMPLES[0].input.parse().unwrap();
         assert_eq!(
             solve(
                 &grid,
@@ -4,6 +4,9 @@ use std::str::FromStr;
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::error::{blocks, column, parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[allow(clippy::upper_case_acronyms)]
 #[derive(PartialEq, Eq, Debug, Hash)]
 enum Field {
@@ -75,16 +78,18 @@ struct Passport {
 }
 
 impl FromStr for Passport {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(pass: &str) -> Result<Self, Self::Err> {
-        let fields = pass
-            .split_whitespace()
-            .map(|kv| {
-                let kv: Vec<_> = kv.split(':').collect();
-                Ok((kv[0].parse()?, kv[1].into()))
-            })
-            .collect::<Result<_, Self::Err>>()?;
+        let mut fields = HashMap::new();
+        for (idx, line) in pass.lines().enumerate() {
+            for kv in line.split_whitespace() {
+                let (key, val) = kv.split_once(':').ok_or_else(|| {
+                    Error::parse(idx + 1, column(line, kv), line, "Expected key:value")
+                })?;
+                fields.insert(parse_field(idx + 1, line, key)?, val.into());
+            }
+        }
         Ok(Self { fields })
     }
 }
@@ -100,17 +105,88 @@ fn solve(passports: &[Passport], check_value: bool) -> usize {
     passports.iter().filter(|p| p.validate(check_value)).count()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d04.txt");
-    let passports = input
-        .split("\n\n")
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = solve(&passports, false);
-    let out2 = solve(&passports, true);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d04.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd\n\
+                byr:1937 iyr:2017 cid:147 hgt:183cm\n\
+                \n\
+                iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884\n\
+                hcl:#cfa07d byr:1929\n\
+                \n\
+                hcl:#ae17e1 iyr:2013\n\
+                eyr:2024\n\
+                ecl:brn pid:760753108 byr:1931\n\
+                hgt:179cm\n\
+                \n\
+                hcl:#cfa07d eyr:2025 pid:166559648\n\
+                iyr:2011 ecl:brn hgt:59in",
+        part1: Some("2"),
+        part2: None,
+    },
+    Example {
+        input: "eyr:1972 cid:100\n\
+                hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926\n\
+                \n\
+                iyr:2019\n\
+                hcl:#602927 eyr:1967 hgt:170cm\n\
+                ecl:grn pid:012533040 byr:1946\n\
+                \n\
+                hcl:dab227 iyr:2012\n\
+                ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277\n\
+                \n\
+                hgt:59cm ecl:zzz\n\
+                eyr:2038 hcl:74454a iyr:2023\n\
+                pid:3556412378 byr:2007",
+        part1: None,
+        part2: Some("0"),
+    },
+    Example {
+        input: "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980\n\
+                hcl:#623a2f\n\
+                \n\
+                eyr:2029 ecl:blu cid:129 byr:1989\n\
+                iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm\n\
+                \n\
+                hcl:#888785\n\
+                hgt:164cm byr:2001 iyr:2015 cid:88\n\
+                pid:545766238 ecl:hzl\n\
+                eyr:2022\n\
+                \n\
+                iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719",
+        part1: None,
+        part2: Some("4"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 4;
+    const TITLE: &'static str = "Passport Processing";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Passport>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        blocks(input)
+            .map(|(skip, pass)| pass.parse().map_err(|err: Error| err.offset(skip)))
+            .collect()
+    }
+
+    fn part1(passports: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(passports, false).into())
+    }
+
+    fn part2(passports: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(passports, true).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,57 +210,14 @@ mod tests {
 
     #[test]
     fn test01() {
-        let passports: Vec<Passport> = [
-            "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd\n\
-             byr:1937 iyr:2017 cid:147 hgt:183cm",
-            "iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884\n\
-             hcl:#cfa07d byr:1929",
-            "hcl:#ae17e1 iyr:2013\n\
-             eyr:2024\n\
-             ecl:brn pid:760753108 byr:1931\n\
-             hgt:179cm",
-            "hcl:#cfa07d eyr:2025 pid:166559648\n\
-             iyr:2011 ecl:brn hgt:59in",
-        ]
-        .iter()
-        .map(|p| p.parse().unwrap())
-        .collect();
+        let passports = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(solve(&passports, false), 2);
     }
 
     #[test]
     fn test02() {
-        let invalid: Vec<Passport> = [
-            "eyr:1972 cid:100\n\
-             hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926",
-            "iyr:2019\n\
-             hcl:#602927 eyr:1967 hgt:170cm\n\
-             ecl:grn pid:012533040 byr:1946",
-            "hcl:dab227 iyr:2012\n\
-             ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277",
-            "hgt:59cm ecl:zzz\n\
-             eyr:2038 hcl:74454a iyr:2023\n\
-             pid:3556412378 byr:2007",
-        ]
-        .iter()
-        .map(|p| p.parse().unwrap())
-        .collect();
-
-        let valid: Vec<Passport> = [
-            "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980\n\
-             hcl:#623a2f",
-            "eyr:2029 ecl:blu cid:129 byr:1989\n\
-             iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm",
-            "hcl:#888785\n\
-             hgt:164cm byr:2001 iyr:2015 cid:88\n\
-             pid:545766238 ecl:hzl\n\
-             eyr:2022",
-            "iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719",
-        ]
-        .iter()
-        .map(|p| p.parse().unwrap())
-        .collect();
-
+        let invalid = Solver::parse(EXAMPLES[1].input).unwrap();
+        let valid = Solver::parse(EXAMPLES[2].input).unwrap();
         assert_eq!(solve(&invalid, true), 0);
         assert_eq!(solve(&valid, true), valid.len());
     }
@@ -1,5 +1,8 @@
 use std::str::FromStr;
 
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(PartialEq, Eq, Debug)]
 struct Ticket {
     row: u8,
@@ -10,6 +13,9 @@ impl FromStr for Ticket {
     type Err = String;
 
     fn from_str(ticket: &str) -> Result<Self, Self::Err> {
+        if ticket.len() != 10 || !ticket.is_ascii() {
+            return Err(format!("Expected 10 characters, got {:?}", ticket));
+        }
         let (row, col) = ticket.split_at(7);
         let row = u8::from_str_radix(&row.replace('F', "0").replace('B', "1"), 2)
             .map_err(|_| "Failed to parse row")?;
@@ -38,33 +44,48 @@ fn solve(tickets: &[Ticket], part1: bool) -> u16 {
     }
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d05.txt");
-    let tickets = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = solve(&tickets, true);
-    let out2 = solve(&tickets, false);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d05.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "BFFFBBFRRR\n\
+            FFFBBBFRRR\n\
+            BBFFBBFRLL",
+    part1: Some("820"),
+    part2: None,
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 5;
+    const TITLE: &'static str = "Binary Boarding";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Ticket>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input)
+    }
+
+    fn part1(tickets: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(tickets, true).into())
+    }
+
+    fn part2(tickets: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(tickets, false).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        assert_eq!(
-            solve(
-                &[
-                    "BFFFBBFRRR".parse().unwrap(),
-                    "FFFBBBFRRR".parse().unwrap(),
-                    "BBFFBBFRLL".parse().unwrap(),
-                ],
-                true
-            ),
-            820
-        );
+        let tickets = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(&tickets, true), 820);
     }
 }
@@ -1,5 +1,8 @@
 use std::collections::HashSet;
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Copy, Clone)]
 enum Mode {
     Any,
@@ -7,10 +10,11 @@ enum Mode {
 }
 use Mode::*;
 
-fn solve(qs: &[&str], mode: Mode) -> usize {
+fn solve<S: AsRef<str>>(qs: &[S], mode: Mode) -> usize {
     qs.iter()
         .map(|q| {
             let mut qs = q
+                .as_ref()
                 .lines()
                 .map(|person| person.chars().collect::<HashSet<_>>());
             let first = qs.next().unwrap();
@@ -23,62 +27,66 @@ fn solve(qs: &[&str], mode: Mode) -> usize {
         .sum()
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d06.txt");
-    let qs: Vec<_> = input.split("\n\n").collect();
-    let out1 = solve(&qs, Any);
-    let out2 = solve(&qs, All);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d06.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "abc\n\
+            \n\
+            a\n\
+            b\n\
+            c\n\
+            \n\
+            ab\n\
+            ac\n\
+            \n\
+            a\n\
+            a\n\
+            a\n\
+            a\n\
+            \n\
+            b",
+    part1: Some("11"),
+    part2: Some("6"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 6;
+    const TITLE: &'static str = "Custom Customs";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.split("\n\n").map(String::from).collect())
+    }
+
+    fn part1(qs: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(qs, Any).into())
+    }
+
+    fn part2(qs: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(qs, All).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        assert_eq!(
-            solve(
-                &[
-                    "abc",
-                    "a\n\
-                     b\n\
-                     c",
-                    "ab\n\
-                     ac",
-                    "a\n\
-                     a\n\
-                     a\n\
-                     a",
-                    "b"
-                ],
-                Any
-            ),
-            11
-        );
+        let qs = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(&qs, Any), 11);
     }
 
     #[test]
     fn test02() {
-        assert_eq!(
-            solve(
-                &[
-                    "abc",
-                    "a\n\
-                     b\n\
-                     c",
-                    "ab\n\
-                     ac",
-                    "a\n\
-                     a\n\
-                     a\n\
-                     a",
-                    "b"
-                ],
-                All
-            ),
-            6
-        );
+        let qs = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(&qs, All), 6);
     }
 }
@@ -1,6 +1,9 @@
 use std::collections::hash_map::{HashMap, Keys};
 use std::str::FromStr;
 
+use crate::error::{column, parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Copy, Clone)]
 enum Mode {
     CanContain,
@@ -13,31 +16,32 @@ struct Rules {
 }
 
 impl FromStr for Rules {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(rules: &str) -> Result<Self, Self::Err> {
         let rules = rules
             .lines()
-            .map(|rule| {
-                let words = rule.split_whitespace().collect::<Vec<_>>();
-                let outer = words[..2].join(" ");
-                let inner = words[4..].join(" ");
-                let inner = if inner.starts_with("no") {
-                    None
-                } else {
-                    Some(
-                        inner
-                            .split(", ")
-                            .map(|rule| rule.split_whitespace().collect::<Vec<_>>())
-                            .map(|rule| {
-                                let n = rule[0].parse::<usize>().unwrap();
-                                let bag = rule[1..3].join(" ");
-                                (bag, n)
-                            })
-                            .collect::<Vec<_>>(),
-                    )
-                };
-                Ok((outer, inner))
+            .enumerate()
+            .map(|(idx, rule)| {
+                let err = |field: &str, msg| Error::parse(idx + 1, column(rule, field), rule, msg);
+                let (outer, inner) = rule
+                    .split_once(" bags contain ")
+                    .ok_or_else(|| err(rule, "Expected \"<bag> bags contain ...\""))?;
+                let inner = inner.trim_end_matches('.');
+                if inner == "no other bags" {
+                    return Ok((outer.into(), None));
+                }
+                let inner = inner
+                    .split(", ")
+                    .map(|bags| {
+                        let (n, bag) = bags
+                            .split_once(' ')
+                            .ok_or_else(|| err(bags, "Expected a number of bags"))?;
+                        let bag = bag.trim_end_matches(" bags").trim_end_matches(" bag");
+                        Ok((bag.into(), parse_field(idx + 1, rule, n)?))
+                    })
+                    .collect::<Result<_, Self::Err>>()?;
+                Ok((outer.into(), Some(inner)))
             })
             .collect::<Result<_, Self::Err>>()?;
         Ok(Self { rules })
@@ -75,46 +79,78 @@ fn solve(rules: &Rules, bag: &str, mode: Mode) -> usize {
     }
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d07.txt");
-    let rules = input.parse::<Rules>()?;
-    let out1 = solve(&rules, "shiny gold", CanContain);
-    let out2 = solve(&rules, "shiny gold", MustContain);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d07.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "light red bags contain 1 bright white bag, 2 muted yellow bags.\n\
+                dark orange bags contain 3 bright white bags, 4 muted yellow bags.\n\
+                bright white bags contain 1 shiny gold bag.\n\
+                muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.\n\
+                shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.\n\
+                dark olive bags contain 3 faded blue bags, 4 dotted black bags.\n\
+                vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.\n\
+                faded blue bags contain no other bags.\n\
+                dotted black bags contain no other bags.",
+        part1: Some("4"),
+        part2: Some("32"),
+    },
+    Example {
+        input: "shiny gold bags contain 2 dark red bags.\n\
+                dark red bags contain 2 dark orange bags.\n\
+                dark orange bags contain 2 dark yellow bags.\n\
+                dark yellow bags contain 2 dark green bags.\n\
+                dark green bags contain 2 dark blue bags.\n\
+                dark blue bags contain 2 dark violet bags.\n\
+                dark violet bags contain no other bags.",
+        part1: None,
+        part2: Some("126"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 7;
+    const TITLE: &'static str = "Handy Haversacks";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Rules;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(rules: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(rules, "shiny gold", CanContain).into())
+    }
+
+    fn part2(rules: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(rules, "shiny gold", MustContain).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const RULES1: &str = "light red bags contain 1 bright white bag, 2 muted yellow bags.\n\
-                          dark orange bags contain 3 bright white bags, 4 muted yellow bags.\n\
-                          bright white bags contain 1 shiny gold bag.\n\
-                          muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.\n\
-                          shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.\n\
-                          dark olive bags contain 3 faded blue bags, 4 dotted black bags.\n\
-                          vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.\n\
-                          faded blue bags contain no other bags.\n\
-                          dotted black bags contain no other bags.";
-
-    const RULES2: &str = "shiny gold bags contain 2 dark red bags.\n\
-                          dark red bags contain 2 dark orange bags.\n\
-                          dark orange bags contain 2 dark yellow bags.\n\
-                          dark yellow bags contain 2 dark green bags.\n\
-                          dark green bags contain 2 dark blue bags.\n\
-                          dark blue bags contain 2 dark violet bags.\n\
-                          dark violet bags contain no other bags.";
-
     #[test]
     fn test_parse() {
-        assert!(RULES1.parse::<Rules>().is_ok());
-        assert!(RULES2.parse::<Rules>().is_ok());
+        assert!(EXAMPLES[0].input.parse::<Rules>().is_ok());
+        assert!(EXAMPLES[1].input.parse::<Rules>().is_ok());
     }
 
     #[test]
     fn test01() {
         assert_eq!(
-            solve(&RULES1.parse::<Rules>().unwrap(), "shiny gold", CanContain),
+            solve(
+                &EXAMPLES[0].input.parse::<Rules>().unwrap(),
+                "shiny gold",
+                CanContain
+            ),
             4
         );
     }
@@ -122,11 +158,19 @@ mod tests {
     #[test]
     fn test02() {
         assert_eq!(
-            solve(&RULES1.parse::<Rules>().unwrap(), "shiny gold", MustContain),
+            solve(
+                &EXAMPLES[0].input.parse::<Rules>().unwrap(),
+                "shiny gold",
+                MustContain
+            ),
             32
         );
         assert_eq!(
-            solve(&RULES2.parse::<Rules>().unwrap(), "shiny gold", MustContain),
+            solve(
+                &EXAMPLES[1].input.parse::<Rules>().unwrap(),
+                "shiny gold",
+                MustContain
+            ),
             126
         );
     }
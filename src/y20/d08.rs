@@ -1,6 +1,10 @@
 use std::collections::HashSet;
 use std::str::FromStr;
 
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
+#[derive(Clone)]
 enum Instruction {
     Nop(i32),
     Acc(i32),
@@ -12,15 +16,17 @@ impl FromStr for Instruction {
     type Err = String;
 
     fn from_str(inst: &str) -> Result<Self, Self::Err> {
-        let inst = inst.split_whitespace().collect::<Vec<_>>();
-        let arg = inst[1]
+        let (op, arg) = inst
+            .split_once(' ')
+            .ok_or_else(|| format!("Expected an instruction and an argument, got {:?}", inst))?;
+        let arg = arg
             .parse::<i32>()
-            .map_err(|_| format!("Invalid argument {}", inst[1]))?;
-        match inst.get(0) {
-            Some(&"nop") => Ok(Nop(arg)),
-            Some(&"acc") => Ok(Acc(arg)),
-            Some(&"jmp") => Ok(Jmp(arg)),
-            _ => Err(format!("Invalid instruction {}", inst[0])),
+            .map_err(|_| format!("Invalid argument {}", arg))?;
+        match op {
+            "nop" => Ok(Nop(arg)),
+            "acc" => Ok(Acc(arg)),
+            "jmp" => Ok(Jmp(arg)),
+            _ => Err(format!("Invalid instruction {}", op)),
         }
     }
 }
@@ -35,17 +41,14 @@ impl Instruction {
     }
 }
 
+#[derive(Clone)]
 struct Instructions(Vec<Instruction>);
 
 impl FromStr for Instructions {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(instrs: &str) -> Result<Self, Self::Err> {
-        let instrs = instrs
-            .lines()
-            .map(str::parse)
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(Self(instrs))
+        Ok(Self(parse_lines(instrs)?))
     }
 }
 
@@ -120,11 +123,11 @@ enum Mode {
 }
 use Mode::*;
 
-fn solve(instrs: &mut Instructions, mode: Mode) -> Result<i32, String> {
+fn solve(instrs: &mut Instructions, mode: Mode) -> Result<i32, Error> {
     match mode {
         DetectLoop => match instrs.prog().run() {
             InfiniteLoop(acc) => Ok(acc),
-            Terminate(_) => Err("No loop found".into()),
+            Terminate(_) => Err(Error::no_solution("No loop found")),
         },
         FixLoop => {
             for idx in 0..instrs.0.len() {
@@ -140,52 +143,65 @@ fn solve(instrs: &mut Instructions, mode: Mode) -> Result<i32, String> {
                     }
                 }
             }
-            Err("No fix found".into())
+            Err(Error::no_solution("No fix found"))
         }
     }
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d08.txt");
-    let mut instrs = input.parse::<Instructions>()?;
-    let out1 = solve(&mut instrs, DetectLoop)?;
-    let out2 = solve(&mut instrs, FixLoop)?;
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d08.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "nop +0\n\
+            acc +1\n\
+            jmp +4\n\
+            acc +3\n\
+            jmp -3\n\
+            acc -99\n\
+            acc +1\n\
+            jmp -4\n\
+            acc +6",
+    part1: Some("5"),
+    part2: Some("8"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 8;
+    const TITLE: &'static str = "Handheld Halting";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Instructions;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(instrs: &Self::Input) -> Result<Answer, Error> {
+        solve(&mut instrs.clone(), DetectLoop).map(Answer::from)
+    }
+
+    fn part2(instrs: &Self::Input) -> Result<Answer, Error> {
+        solve(&mut instrs.clone(), FixLoop).map(Answer::from)
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let mut instrs = "nop +0\n\
-                          acc +1\n\
-                          jmp +4\n\
-                          acc +3\n\
-                          jmp -3\n\
-                          acc -99\n\
-                          acc +1\n\
-                          jmp -4\n\
-                          acc +6"
-            .parse::<Instructions>()
-            .unwrap();
+        let mut instrs = EXAMPLES[0].input.parse::<Instructions>().unwrap();
         assert_eq!(solve(&mut instrs, DetectLoop), Ok(5));
     }
 
     #[test]
     fn test02() {
-        let mut instrs = "nop +0\n\
-                          acc +1\n\
-                          jmp +4\n\
-                          acc +3\n\
-                          jmp -3\n\
-                          acc -99\n\
-                          acc +1\n\
-                          jmp -4\n\
-                          acc +6"
-            .parse::<Instructions>()
-            .unwrap();
+        let mut instrs = EXAMPLES[0].input.parse::<Instructions>().unwrap();
         assert_eq!(solve(&mut instrs, FixLoop), Ok(8));
     }
 }
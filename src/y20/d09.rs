@@ -1,3 +1,6 @@
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 struct Xmas {
     data: Vec<u64>,
 }
@@ -25,21 +28,22 @@ impl Xmas {
     }
 }
 
-fn solve(data: &[u64], width: usize) -> Result<(u64, u64), String> {
+fn part1(data: &[u64], width: usize) -> Result<u64, Error> {
     let (preamble, data) = data.split_at(width);
     let mut xmas = Xmas::new(preamble);
-    let invalid = xmas
-        .find_invalid(data)
-        .ok_or_else(|| "No invalid data found".to_string())?;
+    xmas.find_invalid(data)
+        .ok_or_else(|| Error::no_solution("No invalid data found"))
+}
 
-    let data = [preamble, data].concat();
+fn part2(data: &[u64], width: usize) -> Result<u64, Error> {
+    let invalid = part1(data, width)?;
     let mut sum = 0;
     let mut start = 0;
     for end in 0..data.len() {
         if invalid == sum && 1 <= end - start {
             let min = data[start..end].iter().min().unwrap();
             let max = data[start..end].iter().max().unwrap();
-            return Ok((invalid, min + max));
+            return Ok(min + max);
         }
         sum += data[end];
         while invalid < sum {
@@ -47,29 +51,53 @@ fn solve(data: &[u64], width: usize) -> Result<(u64, u64), String> {
             start += 1;
         }
     }
-    Err("No contiguous sum found".into())
+    Err(Error::no_solution("No contiguous sum found"))
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d09.txt");
-    let data = input
-        .lines()
-        .map(|x| x.parse::<u64>().unwrap())
-        .collect::<Vec<_>>();
-    let (out1, out2) = solve(&data, 25)?;
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d09.txt");
+
+// The example uses a preamble of 5 numbers rather than 25, so it has no
+// answers the solver would give.
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n\
+            102\n117\n150\n182\n127\n219\n299\n277\n309\n576",
+    part1: None,
+    part2: None,
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 9;
+    const TITLE: &'static str = "Encoding Error";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input)
+    }
+
+    fn part1(data: &Self::Input) -> Result<Answer, Error> {
+        part1(data, 25).map(Answer::from)
+    }
+
+    fn part2(data: &Self::Input) -> Result<Answer, Error> {
+        part2(data, 25).map(Answer::from)
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let data = [
-            35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309,
-            576,
-        ];
-        assert_eq!(solve(&data, 5), Ok((127, 62)));
+        let data = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(part1(&data, 5), Ok(127));
+        assert_eq!(part2(&data, 5), Ok(62));
     }
 }
@@ -1,5 +1,8 @@
 use std::collections::HashMap;
 
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 fn distribution(jolts: &[u32]) -> usize {
     let min = jolts[0];
     let diffs = jolts.windows(2).map(|js| js[1] - js[0]);
@@ -22,38 +25,64 @@ fn arrangements(jolts: &[u32]) -> u64 {
     jolts.iter().take_while(|j| **j <= 3).map(|j| memo[j]).sum()
 }
 
-fn solve(jolts: &mut [u32]) -> (usize, u64) {
-    jolts.sort_unstable();
-    (distribution(jolts), arrangements(jolts))
-}
+pub const INPUT: &str = include_str!("input/d10.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4",
+        part1: Some("35"),
+        part2: Some("8"),
+    },
+    Example {
+        input: "28\n33\n18\n42\n31\n14\n46\n20\n48\n47\n24\n23\n49\n45\n19\n38\n\
+                39\n11\n1\n32\n25\n35\n8\n17\n7\n9\n4\n2\n34\n10\n3",
+        part1: Some("220"),
+        part2: Some("19208"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 10;
+    const TITLE: &'static str = "Adapter Array";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<u32>;
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d10.txt");
-    let mut jolts = input
-        .lines()
-        .map(|x| x.parse::<u32>().unwrap())
-        .collect::<Vec<_>>();
-    let (out1, out2) = solve(&mut jolts);
-    Ok(format!("{} {}", out1, out2))
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut jolts = parse_lines::<u32>(input)?;
+        jolts.sort_unstable();
+        Ok(jolts)
+    }
+
+    fn part1(jolts: &Self::Input) -> Result<Answer, Error> {
+        Ok(distribution(jolts).into())
+    }
+
+    fn part2(jolts: &Self::Input) -> Result<Answer, Error> {
+        Ok(arrangements(jolts).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let mut jolts = [16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4];
-        assert_eq!(solve(&mut jolts), (7 * 5, 8));
+        let jolts = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(distribution(&jolts), 7 * 5);
+        assert_eq!(arrangements(&jolts), 8);
     }
 
     #[test]
     fn test02() {
-        let mut jolts = [
-            28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25, 35,
-            8, 17, 7, 9, 4, 2, 34, 10, 3,
-        ];
-        assert_eq!(solve(&mut jolts), (22 * 10, 19208));
+        let jolts = Solver::parse(EXAMPLES[1].input).unwrap();
+        assert_eq!(distribution(&jolts), 22 * 10);
+        assert_eq!(arrangements(&jolts), 19208);
     }
 }
@@ -1,5 +1,8 @@
 use std::str::FromStr;
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 enum Seat {
     Floor,
@@ -98,18 +101,23 @@ use Mode::*;
 struct Grid(Vec<Vec<Seat>>);
 
 impl FromStr for Grid {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(grid: &str) -> Result<Self, Self::Err> {
         let grid = grid
             .lines()
-            .map(|row| {
-                row.chars()
-                    .map(|c| match c {
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| match c {
                         '.' => Ok(Floor),
                         'L' => Ok(Empty),
                         '#' => Ok(Occupied),
-                        _ => Err(format!("Invalid seat {}", c)),
+                        _ => {
+                            let msg = format!("Invalid seat {}", c);
+                            Err(Error::parse(row + 1, col + 1, line, msg))
+                        }
                     })
                     .collect()
             })
@@ -189,20 +197,54 @@ impl Grid {
     }
 }
 
-fn solve(grid: &mut Grid) -> (usize, usize) {
-    let mut grid2 = grid.clone();
-    grid.stabilize(&Adj);
-    grid2.stabilize(&Visible);
-    (grid.count_occupied(), grid2.count_occupied())
+fn solve(grid: &Grid, mode: &Mode) -> usize {
+    let mut grid = grid.clone();
+    grid.stabilize(mode);
+    grid.count_occupied()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d11.txt");
-    let mut grid = input.parse::<Grid>()?;
-    let (out1, out2) = solve(&mut grid);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d11.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "L.LL.LL.LL\n\
+            LLLLLLL.LL\n\
+            L.L.L..L..\n\
+            LLLL.LL.LL\n\
+            L.LL.LL.LL\n\
+            L.LLLLL.LL\n\
+            ..L.L.....\n\
+            LLLLLLLLLL\n\
+            L.LLLLLL.L\n\
+            L.LLLLL.LL",
+    part1: Some("37"),
+    part2: Some("26"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 11;
+    const TITLE: &'static str = "Seating System";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(grid, &Adj).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(grid, &Visible).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -241,18 +283,8 @@ mod tests {
 
     #[test]
     fn test01() {
-        let mut grid = "L.LL.LL.LL\n\
-                        LLLLLLL.LL\n\
-                        L.L.L..L..\n\
-                        LLLL.LL.LL\n\
-                        L.LL.LL.LL\n\
-                        L.LLLLL.LL\n\
-                        ..L.L.....\n\
-                        LLLLLLLLLL\n\
-                        L.LLLLLL.L\n\
-                        L.LLLLL.LL"
-            .parse::<Grid>()
-            .unwrap();
-        assert_eq!(solve(&mut grid), (37, 26));
+        let grid = EXAMPLES[0].input.parse::<Grid>().unwrap();
+        assert_eq!(solve(&grid, &Adj), 37);
+        assert_eq!(solve(&grid, &Visible), 26);
     }
 }
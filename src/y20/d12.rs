@@ -1,6 +1,9 @@
 use std::ops::{Add, AddAssign};
 use std::str::FromStr;
 
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 enum Dir {
     North,
@@ -47,6 +50,9 @@ impl FromStr for Action {
     type Err = String;
 
     fn from_str(act: &str) -> Result<Self, Self::Err> {
+        if !act.is_char_boundary(1) {
+            return Err(format!("Invalid action {:?}", act));
+        }
         let (act, amt) = act.split_at(1);
         let amt = amt
             .parse::<u32>()
@@ -149,42 +155,56 @@ fn solve(acts: &[Action], mode: Mode) -> u32 {
     pos.abs()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d12.txt");
-    let acts = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = solve(&acts, Absolute);
-    let out2 = solve(&acts, Relative);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d12.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "F10\n\
+            N3\n\
+            F7\n\
+            R90\n\
+            F11",
+    part1: Some("25"),
+    part2: Some("286"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 12;
+    const TITLE: &'static str = "Rain Risk";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Action>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input)
+    }
+
+    fn part1(acts: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(acts, Absolute).into())
+    }
+
+    fn part2(acts: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(acts, Relative).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let acts = [
-            Go(None, 10),
-            Go(Some(North), 3),
-            Go(None, 7),
-            Turn(Right, 1),
-            Go(None, 11),
-        ];
+        let acts = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(solve(&acts, Absolute), 25);
     }
 
     #[test]
     fn test02() {
-        let acts = [
-            Go(None, 10),
-            Go(Some(North), 3),
-            Go(None, 7),
-            Turn(Right, 1),
-            Go(None, 11),
-        ];
+        let acts = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(solve(&acts, Relative), 286);
     }
 }
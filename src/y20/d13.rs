@@ -1,3 +1,6 @@
+use crate::error::{parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 // q = gcd(x, y) = x * x0 + y * y0
 #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
 fn euclid(x: u64, y: u64) -> (u64, i64, i64) {
@@ -34,14 +37,14 @@ fn invmod(a: u64, n: u64) -> u64 {
     (x as u64) % n
 }
 
-fn solve(busses: &[Option<u64>], start: Option<u64>) -> Result<u64, String> {
+fn solve(busses: &[Option<u64>], start: Option<u64>) -> Result<u64, Error> {
     if let Some(start) = start {
         let (bus, wait) = busses
             .iter()
             .filter_map(Option::as_ref)
             .map(|bus| (bus, bus * (1 + (start / bus)) - start))
             .min_by_key(|(_, wait)| *wait)
-            .ok_or("No bus found")?;
+            .ok_or_else(|| Error::no_solution("No bus found"))?;
         Ok(bus * wait)
     } else {
         // find t s.t., forall i, t mod mods[i].1 = mods[i].0
@@ -61,43 +64,68 @@ fn solve(busses: &[Option<u64>], start: Option<u64>) -> Result<u64, String> {
     }
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d13.txt");
-    let mut lines = input.lines();
-    let start = lines.next().unwrap().parse::<u64>().unwrap();
-    let busses = lines
-        .next()
-        .unwrap()
-        .split(',')
-        .map(|bus| bus.parse::<u64>().ok())
-        .collect::<Vec<_>>();
-    let out1 = solve(&busses, Some(start))?;
-    let out2 = solve(&busses, None)?;
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d13.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "939\n\
+            7,13,x,x,59,x,31,19",
+    part1: Some("295"),
+    part2: Some("1068781"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 13;
+    const TITLE: &'static str = "Shuttle Search";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (u64, Vec<Option<u64>>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut lines = input.lines();
+        let start = lines.next().unwrap_or_default();
+        let start = parse_field(1, start, start)?;
+        let line = lines
+            .next()
+            .ok_or_else(|| Error::parse(2, 1, "", "Expected a list of busses"))?;
+        let busses = line
+            .split(',')
+            .map(|bus| match bus {
+                "x" => Ok(None),
+                _ => parse_field(2, line, bus).map(Some),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((start, busses))
+    }
+
+    fn part1((start, busses): &Self::Input) -> Result<Answer, Error> {
+        solve(busses, Some(*start)).map(Answer::from)
+    }
+
+    fn part2((_, busses): &Self::Input) -> Result<Answer, Error> {
+        solve(busses, None).map(Answer::from)
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let busses = [
-            Some(7),
-            Some(13),
-            None,
-            None,
-            Some(59),
-            None,
-            Some(31),
-            Some(19),
-        ];
-        assert_eq!(solve(&busses, Some(939)), Ok(295));
+        let (start, busses) = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(&busses, Some(start)), Ok(295));
     }
 
     #[allow(clippy::shadow_unrelated)]
     #[test]
     fn test02() {
+        let (_, busses) = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(&busses, None), Ok(1_068_781));
         let busses = [Some(17), None, Some(13), Some(19)];
         assert_eq!(solve(&busses, None), Ok(3417));
         let busses = [Some(67), Some(7), Some(59), Some(61)];
@@ -4,6 +4,9 @@ use std::iter;
 use std::ops::{BitAnd, BitOr};
 use std::str::FromStr;
 
+use crate::error::{column, parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(PartialEq, Eq, Debug, Clone, Hash)]
 struct Mask(Vec<Option<bool>>);
 
@@ -116,25 +119,6 @@ impl Mask {
 
 type Seq = (Mask, Vec<(u64, u64)>);
 
-fn parse_seq(seq: &str) -> Result<Seq, String> {
-    let mut lines = seq.lines();
-    let mask = lines.next().unwrap().parse::<Mask>()?;
-    let mems = lines
-        .map(|line| {
-            let line = line.split(" = ").collect::<Vec<_>>();
-            let mem = line[0]
-                .chars()
-                .filter(|c| c.is_digit(10))
-                .collect::<String>()
-                .parse::<u64>()
-                .unwrap();
-            let val = line[1].parse::<u64>().unwrap();
-            (mem, val)
-        })
-        .collect::<Vec<_>>();
-    Ok((mask, mems))
-}
-
 #[derive(Copy, Clone)]
 enum Mode {
     V1,
@@ -165,18 +149,79 @@ fn solve(seqs: &[Seq], mode: Mode) -> u64 {
     mem.values().sum()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d14.txt");
-    let seqs = input.split("mask = ").collect::<Vec<_>>();
-    let seqs = seqs[1..]
-        .iter()
-        .map(|seq| parse_seq(seq))
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = solve(&seqs, V1);
-    let out2 = solve(&seqs, V2);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d14.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\n\
+                mem[8] = 11\n\
+                mem[7] = 101\n\
+                mem[8] = 0",
+        part1: Some("165"),
+        part2: None,
+    },
+    Example {
+        input: "mask = 000000000000000000000000000000X1001X\n\
+                mem[42] = 100\n\
+                mask = 00000000000000000000000000000000X0XX\n\
+                mem[26] = 1",
+        part1: None,
+        part2: Some("208"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 14;
+    const TITLE: &'static str = "Docking Data";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Seq>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut seqs: Vec<Seq> = vec![];
+        for (row, line) in input.lines().enumerate() {
+            let lineno = row + 1;
+            let (lhs, rhs) = line
+                .split_once(" = ")
+                .ok_or_else(|| Error::parse(lineno, 1, line, "Expected an assignment"))?;
+            if lhs == "mask" {
+                let mask = rhs
+                    .parse()
+                    .map_err(|msg| Error::parse(lineno, column(line, rhs), line, msg))?;
+                seqs.push((mask, vec![]));
+            } else {
+                let addr = lhs
+                    .strip_prefix("mem[")
+                    .and_then(|addr| addr.strip_suffix(']'))
+                    .ok_or_else(|| {
+                        Error::parse(lineno, 1, line, "Expected mask or mem[address]")
+                    })?;
+                let (_, mems) = seqs
+                    .last_mut()
+                    .ok_or_else(|| Error::parse(lineno, 1, line, "Expected a mask first"))?;
+                mems.push((
+                    parse_field(lineno, line, addr)?,
+                    parse_field(lineno, line, rhs)?,
+                ));
+            }
+        }
+        Ok(seqs)
+    }
+
+    fn part1(seqs: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(seqs, V1).into())
+    }
+
+    fn part2(seqs: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(seqs, V2).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,17 +234,13 @@ mod tests {
 
     #[test]
     fn test01() {
-        let mask: Mask = "XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X".parse().unwrap();
-        let mems = vec![(8, 11), (7, 101), (8, 0)];
-        assert_eq!(solve(&[(mask, mems)], V1), 165);
+        let seqs = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(&seqs, V1), 165);
     }
 
     #[test]
     fn test02() {
-        let mask1: Mask = "000000000000000000000000000000X1001X".parse().unwrap();
-        let mems1 = vec![(42, 100)];
-        let mask2: Mask = "00000000000000000000000000000000X0XX".parse().unwrap();
-        let mems2 = vec![(26, 1)];
-        assert_eq!(solve(&[(mask1, mems1), (mask2, mems2)], V2), 208);
+        let seqs = Solver::parse(EXAMPLES[1].input).unwrap();
+        assert_eq!(solve(&seqs, V2), 208);
     }
 }
@@ -1,5 +1,8 @@
 use std::collections::HashMap;
 
+use crate::error::{parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 struct Seq {
     rounds: HashMap<usize, (usize, usize)>,
     turn: usize,
@@ -40,44 +43,112 @@ fn solve(seq: &[usize], n: usize) -> usize {
     seq.nth(n).unwrap()
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d15.txt");
-    let seq = input
-        .trim()
-        .split(',')
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect::<Vec<_>>();
-    let out1 = solve(&seq, 2020);
-    let out2 = solve(&seq, 30_000_000);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d15.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "0,3,6",
+        part1: Some("436"),
+        part2: Some("175594"),
+    },
+    Example {
+        input: "1,3,2",
+        part1: Some("1"),
+        part2: Some("2578"),
+    },
+    Example {
+        input: "2,1,3",
+        part1: Some("10"),
+        part2: Some("3544142"),
+    },
+    Example {
+        input: "1,2,3",
+        part1: Some("27"),
+        part2: Some("261214"),
+    },
+    Example {
+        input: "2,3,1",
+        part1: Some("78"),
+        part2: Some("6895259"),
+    },
+    Example {
+        input: "3,2,1",
+        part1: Some("438"),
+        part2: Some("18"),
+    },
+    Example {
+        input: "3,1,2",
+        part1: Some("1836"),
+        part2: Some("362"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 15;
+    const TITLE: &'static str = "Rambunctious Recitation";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let line = input.trim();
+        line.split(',').map(|x| parse_field(1, line, x)).collect()
+    }
+
+    fn part1(seq: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(seq, 2020).into())
+    }
+
+    fn part2(seq: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(seq, 30_000_000).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        assert_eq!(solve(&[0, 3, 6], 10), 0);
-        assert_eq!(solve(&[1, 3, 2], 2020), 1);
-        assert_eq!(solve(&[2, 1, 3], 2020), 10);
-        assert_eq!(solve(&[1, 2, 3], 2020), 27);
-        assert_eq!(solve(&[2, 3, 1], 2020), 78);
-        assert_eq!(solve(&[3, 2, 1], 2020), 438);
-        assert_eq!(solve(&[3, 1, 2], 2020), 1836);
+        let seq = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(&seq, 10), 0);
+        assert_eq!(solve(&seq, 2020), 436);
+        let seq = Solver::parse(EXAMPLES[1].input).unwrap();
+        assert_eq!(solve(&seq, 2020), 1);
+        let seq = Solver::parse(EXAMPLES[2].input).unwrap();
+        assert_eq!(solve(&seq, 2020), 10);
+        let seq = Solver::parse(EXAMPLES[3].input).unwrap();
+        assert_eq!(solve(&seq, 2020), 27);
+        let seq = Solver::parse(EXAMPLES[4].input).unwrap();
+        assert_eq!(solve(&seq, 2020), 78);
+        let seq = Solver::parse(EXAMPLES[5].input).unwrap();
+        assert_eq!(solve(&seq, 2020), 438);
+        let seq = Solver::parse(EXAMPLES[6].input).unwrap();
+        assert_eq!(solve(&seq, 2020), 1836);
     }
 
     // Too slow
     #[test]
     #[ignore]
     fn test02() {
-        assert_eq!(solve(&[0, 3, 6], 30_000_000), 175_594);
-        assert_eq!(solve(&[1, 3, 2], 30_000_000), 2578);
-        assert_eq!(solve(&[2, 1, 3], 30_000_000), 3_544_142);
-        assert_eq!(solve(&[1, 2, 3], 30_000_000), 261_214);
-        assert_eq!(solve(&[2, 3, 1], 30_000_000), 6_895_259);
-        assert_eq!(solve(&[3, 2, 1], 30_000_000), 18);
-        assert_eq!(solve(&[3, 1, 2], 30_000_000), 362);
+        let seq = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(&seq, 30_000_000), 175_594);
+        let seq = Solver::parse(EXAMPLES[1].input).unwrap();
+        assert_eq!(solve(&seq, 30_000_000), 2578);
+        let seq = Solver::parse(EXAMPLES[2].input).unwrap();
+        assert_eq!(solve(&seq, 30_000_000), 3_544_142);
+        let seq = Solver::parse(EXAMPLES[3].input).unwrap();
+        assert_eq!(solve(&seq, 30_000_000), 261_214);
+        let seq = Solver::parse(EXAMPLES[4].input).unwrap();
+        assert_eq!(solve(&seq, 30_000_000), 6_895_259);
+        let seq = Solver::parse(EXAMPLES[5].input).unwrap();
+        assert_eq!(solve(&seq, 30_000_000), 18);
+        let seq = Solver::parse(EXAMPLES[6].input).unwrap();
+        assert_eq!(solve(&seq, 30_000_000), 362);
     }
 }
@@ -2,6 +2,9 @@ use std::cmp::PartialOrd;
 use std::collections::HashMap;
 use std::str::FromStr;
 
+use crate::error::{blocks, column, parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Clone)]
 struct Range<A>(A, A);
 
@@ -15,35 +18,38 @@ impl<A: FromStr> FromStr for Range<A> {
     type Err = String;
 
     fn from_str(range: &str) -> Result<Self, Self::Err> {
-        let mut range = range
-            .split('-')
-            .map(|x| x.parse::<A>().map_err(|_| "Invalid range"));
-        let min = range.next().unwrap()?;
-        let max = range.next().unwrap()?;
-        Ok(Self(min, max))
+        let (min, max) = range.split_once('-').ok_or("Expected a range")?;
+        match (min.parse(), max.parse()) {
+            (Ok(min), Ok(max)) => Ok(Self(min, max)),
+            _ => Err("Invalid range".to_string()),
+        }
     }
 }
 
 struct Fields(HashMap<String, Vec<Range<u64>>>);
 
 impl FromStr for Fields {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(fields: &str) -> Result<Self, Self::Err> {
         let fields = fields
             .lines()
-            .map(|field| {
-                let mut field = field.split(": ");
-                let name = field.next().unwrap();
-                let ranges = field
-                    .next()
-                    .unwrap()
+            .enumerate()
+            .map(|(row, line)| {
+                let (name, ranges) = line
+                    .split_once(": ")
+                    .ok_or_else(|| Error::parse(row + 1, 1, line, "Expected a field"))?;
+                let ranges = ranges
                     .split(" or ")
-                    .map(str::parse)
+                    .map(|range| {
+                        range
+                            .parse()
+                            .map_err(|msg| Error::parse(row + 1, column(line, range), line, msg))
+                    })
                     .collect::<Result<_, _>>()?;
                 Ok((name.to_string(), ranges))
             })
-            .collect::<Result<_, String>>()?;
+            .collect::<Result<_, Error>>()?;
         Ok(Self(fields))
     }
 }
@@ -63,17 +69,15 @@ impl Fields {
 
 struct Ticket(Vec<u64>);
 
-impl FromStr for Ticket {
-    type Err = String;
-
-    fn from_str(tick: &str) -> Result<Self, Self::Err> {
-        Ok(Self(
-            tick.split(',').map(|x| x.parse::<u64>().unwrap()).collect(),
-        ))
+impl Ticket {
+    fn parse(lineno: usize, line: &str) -> Result<Self, Error> {
+        let vals = line
+            .split(',')
+            .map(|val| parse_field(lineno, line, val))
+            .collect::<Result<_, _>>()?;
+        Ok(Self(vals))
     }
-}
 
-impl Ticket {
     fn invalid(&self, fields: &Fields) -> Vec<u64> {
         self.0
             .iter()
@@ -145,72 +149,109 @@ fn solve(fields: &Fields, mytick: &Ticket, ticks: &[Ticket], mode: Mode) -> u64
     }
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d16.txt");
-    let mut sections = input.split("\n\n");
-    let fields = sections.next().unwrap().parse::<Fields>()?;
-    let mytick = sections
-        .next()
-        .unwrap()
-        .lines()
-        .nth(1)
-        .unwrap()
-        .parse::<Ticket>()?;
-    let ticks = sections
-        .next()
-        .unwrap()
-        .lines()
-        .skip(1)
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = solve(&fields, &mytick, &ticks, ErrorRate);
-    let out2 = solve(&fields, &mytick, &ticks, IdentifyFields);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d16.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "class: 1-3 or 5-7\n\
+                row: 6-11 or 33-44\n\
+                seat: 13-40 or 45-50\n\
+                \n\
+                your ticket:\n\
+                7,1,14\n\
+                \n\
+                nearby tickets:\n\
+                7,3,47\n\
+                40,4,50\n\
+                55,2,20\n\
+                38,6,12",
+        part1: Some("71"),
+        part2: None,
+    },
+    Example {
+        input: "class: 0-1 or 4-19\n\
+                row: 0-5 or 8-19\n\
+                seat: 0-13 or 16-19\n\
+                \n\
+                your ticket:\n\
+                11,12,13\n\
+                \n\
+                nearby tickets:\n\
+                3,9,18\n\
+                15,1,5\n\
+                5,14,9",
+        part1: None,
+        part2: None,
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 16;
+    const TITLE: &'static str = "Ticket Translation";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (Fields, Ticket, Vec<Ticket>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let end = input.lines().count() + 1;
+        let mut blocks = blocks(input);
+        let (_, fields) = blocks.next().unwrap_or_default();
+        let fields = fields.parse::<Fields>()?;
+        // The tickets in the block under `header`, with their line numbers.
+        let mut tickets = |header: &str| {
+            let expected = || format!("Expected {:?}", header);
+            let (skip, block) = blocks
+                .next()
+                .ok_or_else(|| Error::parse(end, 1, "", expected()))?;
+            let mut lines = block.lines();
+            match lines.next() {
+                Some(line) if line == header => lines
+                    .enumerate()
+                    .map(|(idx, line)| Ticket::parse(skip + idx + 2, line))
+                    .collect::<Result<Vec<_>, _>>(),
+                line => Err(Error::parse(
+                    skip + 1,
+                    1,
+                    line.unwrap_or_default(),
+                    expected(),
+                )),
+            }
+        };
+        let mytick = tickets("your ticket:")?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::parse(end, 1, "", "Expected your ticket"))?;
+        let ticks = tickets("nearby tickets:")?;
+        Ok((fields, mytick, ticks))
+    }
+
+    fn part1((fields, mytick, ticks): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(fields, mytick, ticks, ErrorRate).into())
+    }
+
+    fn part2((fields, mytick, ticks): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(fields, mytick, ticks, IdentifyFields).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let fields = Fields(
-            [
-                ("class".to_string(), vec![Range(1, 3), Range(5, 7)]),
-                ("row".to_string(), vec![Range(6, 11), Range(33, 44)]),
-                ("seat".to_string(), vec![Range(13, 40), Range(45, 50)]),
-            ]
-            .iter()
-            .cloned()
-            .collect(),
-        );
-        let mytick = Ticket(vec![7, 1, 14]);
-        let ticks = vec![
-            Ticket(vec![7, 3, 47]),
-            Ticket(vec![40, 4, 50]),
-            Ticket(vec![55, 2, 20]),
-            Ticket(vec![38, 6, 12]),
-        ];
+        let (fields, mytick, ticks) = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(solve(&fields, &mytick, &ticks, ErrorRate), 71);
     }
 
     #[test]
     fn test02() {
-        let fields = Fields(
-            [
-                ("class".to_string(), vec![Range(0, 1), Range(4, 19)]),
-                ("row".to_string(), vec![Range(0, 5), Range(8, 19)]),
-                ("seat".to_string(), vec![Range(0, 13), Range(16, 19)]),
-            ]
-            .iter()
-            .cloned()
-            .collect(),
-        );
-        let ticks = vec![
-            Ticket(vec![3, 9, 18]),
-            Ticket(vec![15, 1, 5]),
-            Ticket(vec![5, 14, 9]),
-        ];
+        let (fields, _, ticks) = Solver::parse(EXAMPLES[1].input).unwrap();
         assert_eq!(
             identify_fields(&fields, &ticks),
             vec!["row", "class", "seat"]
@@ -5,6 +5,9 @@ use std::marker::PhantomData;
 use std::ops::Add;
 use std::str::FromStr;
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 trait Dimension: core::fmt::Debug + Clone + Eq + Hash {
     const DIMS: u8;
 }
@@ -98,21 +101,23 @@ struct Cube<D: Dimension> {
 }
 
 impl<D: Dimension> FromStr for Cube<D> {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(plane: &str) -> Result<Self, Self::Err> {
-        let points = plane
-            .lines()
-            .rev()
-            .enumerate()
-            .flat_map(|(y, row)| {
-                row.chars().enumerate().filter_map(move |(x, c)| match c {
-                    '#' => Some(Point::new2(x as i64, y as i64)),
-                    '.' => None,
-                    _ => unreachable!("Invalid char {}", c),
-                })
-            })
-            .collect::<HashSet<_>>();
+        let rows = plane.lines().count();
+        let mut points = HashSet::new();
+        for (row, line) in plane.lines().enumerate() {
+            let y = (rows - 1 - row) as i64;
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        points.insert(Point::new2(x as i64, y));
+                    }
+                    '.' => {}
+                    _ => return Err(Error::parse(row + 1, x + 1, line, "Invalid cube")),
+                }
+            }
+        }
         Ok(Self { points })
     }
 }
@@ -161,15 +166,42 @@ fn solve<D: Dimension>(cube: Cube<D>) -> usize {
     cube.boot().active()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d17.txt");
-    let cube = input.parse::<Cube<D3>>()?;
-    let out1 = solve(cube);
-    let cube = input.parse::<Cube<D4>>()?;
-    let out2 = solve(cube);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d17.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: ".#.\n\
+            ..#\n\
+            ###",
+    part1: Some("112"),
+    part2: Some("848"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 17;
+    const TITLE: &'static str = "Conway Cubes";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (Cube<D3>, Cube<D4>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let cube = input.parse::<Cube<D3>>()?;
+        Ok((cube, input.parse()?))
+    }
+
+    fn part1((cube, _): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(cube.clone()).into())
+    }
+
+    fn part2((_, cube): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(cube.clone()).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,22 +218,14 @@ mod tests {
 
     #[test]
     fn test01() {
-        let cube = ".#.\n\
-                    ..#\n\
-                    ###"
-        .parse::<Cube<D3>>()
-        .unwrap();
+        let cube = EXAMPLES[0].input.parse::<Cube<D3>>().unwrap();
         assert_eq!(solve(cube), 112);
     }
 
     #[ignore]
     #[test]
     fn test02() {
-        let cube = ".#.\n\
-                    ..#\n\
-                    ###"
-        .parse::<Cube<D4>>()
-        .unwrap();
+        let cube = EXAMPLES[0].input.parse::<Cube<D4>>().unwrap();
         assert_eq!(solve(cube), 848);
     }
 }
@@ -2,8 +2,11 @@ use std::collections::VecDeque;
 use std::convert::TryFrom;
 use std::str::FromStr;
 
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Copy, Clone)]
-enum Op {
+pub enum Op {
     Plus,
     Mult,
 }
@@ -21,13 +24,15 @@ impl FromStr for Token {
     type Err = String;
 
     fn from_str(e: &str) -> Result<Self, Self::Err> {
-        match e.chars().next().unwrap() {
-            '(' => Ok(LParen),
-            ')' => Ok(RParen),
-            '+' => Ok(Op(Op::Plus)),
-            '*' => Ok(Op(Op::Mult)),
-            '0'..='9' => Ok(Number(e.parse::<i64>().unwrap())),
-            _ => Err(format!("Invalid token {}", e)),
+        match e {
+            "(" => Ok(LParen),
+            ")" => Ok(RParen),
+            "+" => Ok(Op(Op::Plus)),
+            "*" => Ok(Op(Op::Mult)),
+            _ => e
+                .parse()
+                .map(Number)
+                .map_err(|_| format!("Invalid token {}", e)),
         }
     }
 }
@@ -53,14 +58,14 @@ impl FromStr for Lexer {
 }
 
 #[derive(Debug)]
-enum Expr {
+pub enum Expr {
     BinOp(Op, Box<Expr>, Box<Expr>),
     Scalar(i64),
 }
 use Expr::*;
 
 impl Expr {
-    fn eval(&self) -> i64 {
+    pub fn eval(&self) -> i64 {
         match self {
             BinOp(op, lhs, rhs) => {
                 let lhs = lhs.eval();
@@ -75,7 +80,7 @@ impl Expr {
     }
 }
 
-struct SamePrec(Expr);
+pub struct SamePrec(pub Expr);
 
 impl FromStr for SamePrec {
     type Err = String;
@@ -140,7 +145,7 @@ impl SamePrec {
     }
 }
 
-struct DiffPrec(Expr);
+pub struct DiffPrec(pub Expr);
 
 impl FromStr for DiffPrec {
     type Err = String;
@@ -229,21 +234,71 @@ fn solve(exps: &[Expr]) -> i64 {
     exps.iter().map(Expr::eval).sum()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d18.txt");
-    let exps = input
-        .lines()
-        .map(|x| x.parse::<SamePrec>().map(|e| e.0))
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = solve(&exps);
-    let exps = input
-        .lines()
-        .map(|x| x.parse::<DiffPrec>().map(|e| e.0))
-        .collect::<Result<Vec<_>, _>>()?;
-    let out2 = solve(&exps);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d18.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "1 + 2 * 3 + 4 * 5 + 6",
+        part1: Some("71"),
+        part2: Some("231"),
+    },
+    Example {
+        input: "1 + (2 * 3) + (4 * (5 + 6))",
+        part1: Some("51"),
+        part2: Some("51"),
+    },
+    Example {
+        input: "2 * 3 + (4 * 5)",
+        part1: Some("26"),
+        part2: Some("46"),
+    },
+    Example {
+        input: "5 + (8 * 3 + 9 + 3 * 4 * 3)",
+        part1: Some("437"),
+        part2: Some("1445"),
+    },
+    Example {
+        input: "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))",
+        part1: Some("12240"),
+        part2: Some("669060"),
+    },
+    Example {
+        input: "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
+        part1: Some("13632"),
+        part2: Some("23340"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 18;
+    const TITLE: &'static str = "Operation Order";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (Vec<Expr>, Vec<Expr>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let same = parse_lines::<SamePrec>(input)?;
+        let diff = parse_lines::<DiffPrec>(input)?;
+        Ok((
+            same.into_iter().map(|e| e.0).collect(),
+            diff.into_iter().map(|e| e.0).collect(),
+        ))
+    }
+
+    fn part1((exps, _): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(exps).into())
+    }
+
+    fn part2((_, exps): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(exps).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,93 +307,19 @@ mod tests {
     fn test01() {
         assert_eq!("2".parse::<SamePrec>().unwrap().0.eval(), 2);
         assert_eq!("(2)".parse::<SamePrec>().unwrap().0.eval(), 2);
-        assert_eq!(
-            "1 + 2 * 3 + 4 * 5 + 6"
-                .parse::<SamePrec>()
-                .unwrap()
-                .0
-                .eval(),
-            71
-        );
-        assert_eq!(
-            "1 + (2 * 3) + (4 * (5 + 6))"
-                .parse::<SamePrec>()
-                .unwrap()
-                .0
-                .eval(),
-            51
-        );
-        assert_eq!("2 * 3 + (4 * 5)".parse::<SamePrec>().unwrap().0.eval(), 26);
-        assert_eq!(
-            "5 + (8 * 3 + 9 + 3 * 4 * 3)"
-                .parse::<SamePrec>()
-                .unwrap()
-                .0
-                .eval(),
-            437
-        );
-        assert_eq!(
-            "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"
-                .parse::<SamePrec>()
-                .unwrap()
-                .0
-                .eval(),
-            12240
-        );
-        assert_eq!(
-            "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"
-                .parse::<SamePrec>()
-                .unwrap()
-                .0
-                .eval(),
-            13632
-        );
+        for (example, answer) in EXAMPLES.iter().zip(&[71, 51, 26, 437, 12240, 13632]) {
+            let (exps, _) = Solver::parse(example.input).unwrap();
+            assert_eq!(solve(&exps), *answer);
+        }
     }
 
     #[test]
     fn test02() {
         assert_eq!("2".parse::<DiffPrec>().unwrap().0.eval(), 2);
         assert_eq!("(2)".parse::<DiffPrec>().unwrap().0.eval(), 2);
-        assert_eq!(
-            "1 + 2 * 3 + 4 * 5 + 6"
-                .parse::<DiffPrec>()
-                .unwrap()
-                .0
-                .eval(),
-            231
-        );
-        assert_eq!(
-            "1 + (2 * 3) + (4 * (5 + 6))"
-                .parse::<DiffPrec>()
-                .unwrap()
-                .0
-                .eval(),
-            51
-        );
-        assert_eq!("2 * 3 + (4 * 5)".parse::<DiffPrec>().unwrap().0.eval(), 46);
-        assert_eq!(
-            "5 + (8 * 3 + 9 + 3 * 4 * 3)"
-                .parse::<DiffPrec>()
-                .unwrap()
-                .0
-                .eval(),
-            1445
-        );
-        assert_eq!(
-            "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"
-                .parse::<DiffPrec>()
-                .unwrap()
-                .0
-                .eval(),
-            669_060
-        );
-        assert_eq!(
-            "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"
-                .parse::<DiffPrec>()
-                .unwrap()
-                .0
-                .eval(),
-            23340
-        );
+        for (example, answer) in EXAMPLES.iter().zip(&[231, 51, 46, 1445, 669_060, 23340]) {
+            let (_, exps) = Solver::parse(example.input).unwrap();
+            assert_eq!(solve(&exps), *answer);
+        }
     }
 }
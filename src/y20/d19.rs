@@ -2,6 +2,9 @@ use std::collections::HashMap;
 use std::fmt;
 use std::str::FromStr;
 
+use crate::error::{parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone)]
 enum Rule {
     Char(char),
@@ -18,21 +21,28 @@ impl FromStr for Rule {
         let mut rules = rule
             .split(" | ")
             .map(|rule| {
-                if rule.starts_with('"') {
-                    Char(rule.chars().nth(1).unwrap())
+                if let Some(c) = rule.strip_prefix('"') {
+                    match c.chars().collect::<Vec<_>>()[..] {
+                        [c, '"'] => Ok(Char(c)),
+                        _ => Err(format!("Invalid character rule {}", rule)),
+                    }
                 } else {
                     let mut rules = rule
                         .split_whitespace()
-                        .map(|r| Ref(r.parse::<u32>().unwrap()))
-                        .collect::<Vec<_>>();
-                    if rules.len() == 1 {
-                        rules.remove(0)
-                    } else {
-                        Concat(rules)
+                        .map(|r| {
+                            r.parse()
+                                .map(Ref)
+                                .map_err(|_| format!("Invalid rule reference {}", r))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    match rules.len() {
+                        0 => Err("Empty rule".to_string()),
+                        1 => Ok(rules.remove(0)),
+                        _ => Ok(Concat(rules)),
                     }
                 }
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
         Ok(if rules.len() == 1 {
             rules.remove(0)
         } else {
@@ -70,7 +80,7 @@ fn non_empty<A>(xs: Vec<A>) -> Option<Vec<A>> {
     (!xs.is_empty()).then(|| xs)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Rules(HashMap<u32, Rule>);
 
 impl Rules {
@@ -101,39 +111,131 @@ impl Rules {
 }
 
 impl FromStr for Rules {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(rules: &str) -> Result<Self, Self::Err> {
         Ok(Self(
             rules
                 .lines()
-                .map(|line| {
-                    let line = line.split(": ").collect::<Vec<_>>();
-                    let n = line[0].parse::<u32>().unwrap();
-                    let rule = line[1].parse::<Rule>()?;
-                    Ok((n, rule))
+                .enumerate()
+                .map(|(row, line)| {
+                    let (n, rule) = line
+                        .split_once(": ")
+                        .ok_or_else(|| Error::parse(row + 1, 1, line, "Expected a rule"))?;
+                    Ok((
+                        parse_field(row + 1, line, n)?,
+                        parse_field(row + 1, line, rule)?,
+                    ))
                 })
                 .collect::<Result<_, Self::Err>>()?,
         ))
     }
 }
 
-fn solve(rules: &Rules, msgs: &[&str]) -> usize {
-    msgs.iter().filter(|msg| rules.matches(0, msg)).count()
+fn solve<S: AsRef<str>>(rules: &Rules, msgs: &[S]) -> usize {
+    msgs.iter()
+        .filter(|msg| rules.matches(0, msg.as_ref()))
+        .count()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d19.txt");
-    let blocks = input.split("\n\n").collect::<Vec<_>>();
-    let mut rules = blocks[0].parse::<Rules>()?;
-    let msgs = blocks[1].lines().collect::<Vec<_>>();
-    let out1 = solve(&rules, &msgs);
-    rules.0.insert(8, "42 | 42 8".parse::<Rule>()?);
-    rules.0.insert(11, "42 31 | 42 11 31".parse::<Rule>()?);
-    let out2 = solve(&rules, &msgs);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d19.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "42: 9 14 | 10 1\n\
+            9: 14 27 | 1 26\n\
+            10: 23 14 | 28 1\n\
+            1: \"a\"\n\
+            11: 42 31\n\
+            5: 1 14 | 15 1\n\
+            19: 14 1 | 14 14\n\
+            12: 24 14 | 19 1\n\
+            16: 15 1 | 14 14\n\
+            31: 14 17 | 1 13\n\
+            6: 14 14 | 1 14\n\
+            2: 1 24 | 14 4\n\
+            0: 8 11\n\
+            13: 14 3 | 1 12\n\
+            15: 1 | 14\n\
+            17: 14 2 | 1 7\n\
+            23: 25 1 | 22 14\n\
+            28: 16 1\n\
+            4: 1 1\n\
+            20: 14 14 | 1 15\n\
+            3: 5 14 | 16 1\n\
+            27: 1 6 | 14 18\n\
+            14: \"b\"\n\
+            21: 14 1 | 1 14\n\
+            25: 1 1 | 1 14\n\
+            22: 14 14\n\
+            8: 42\n\
+            26: 14 22 | 1 20\n\
+            18: 15 15\n\
+            7: 14 5 | 1 21\n\
+            24: 14 1\n\
+            \n\
+            abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa\n\
+            bbabbbbaabaabba\n\
+            babbbbaabbbbbabbbbbbaabaaabaaa\n\
+            aaabbbbbbaaaabaababaabababbabaaabbababababaaa\n\
+            bbbbbbbaaaabbbbaaabbabaaa\n\
+            bbbababbbbaaaaaaaabbababaaababaabab\n\
+            ababaaaaaabaaab\n\
+            ababaaaaabbbaba\n\
+            baabbaaaabbaaaababbaababb\n\
+            abbbbabbbbaaaababbbbbbaaaababb\n\
+            aaaaabbaabaaaaababaa\n\
+            aaaabbaaaabbaaa\n\
+            aaaabbaabbaaaaaaabbbabbbaaabbaabaaa\n\
+            babaaabbbaaabaababbaabababaaab\n\
+            aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba",
+    part1: Some("3"),
+    part2: Some("12"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 19;
+    const TITLE: &'static str = "Monster Messages";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (Rules, Vec<String>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut blocks = input.split("\n\n");
+        let rules = blocks.next().unwrap_or_default().parse::<Rules>()?;
+        let msgs = blocks
+            .next()
+            .map(|msgs| msgs.lines().map(String::from).collect())
+            .unwrap_or_default();
+        Ok((rules, msgs))
+    }
+
+    fn part1((rules, msgs): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(rules, msgs).into())
+    }
+
+    fn part2((rules, msgs): &Self::Input) -> Result<Answer, Error> {
+        let mut rules = rules.clone();
+        // 8: 42 | 42 8
+        rules
+            .0
+            .insert(8, Alt(vec![Ref(42), Concat(vec![Ref(42), Ref(8)])]));
+        // 11: 42 31 | 42 11 31
+        rules.0.insert(
+            11,
+            Alt(vec![
+                Concat(vec![Ref(42), Ref(31)]),
+                Concat(vec![Ref(42), Ref(11), Ref(31)]),
+            ]),
+        );
+        Ok(solve(&rules, msgs).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,56 +279,7 @@ mod tests {
 
     #[test]
     fn test02() {
-        let mut rules = "42: 9 14 | 10 1\n\
-                         9: 14 27 | 1 26\n\
-                         10: 23 14 | 28 1\n\
-                         1: \"a\"\n\
-                         11: 42 31\n\
-                         5: 1 14 | 15 1\n\
-                         19: 14 1 | 14 14\n\
-                         12: 24 14 | 19 1\n\
-                         16: 15 1 | 14 14\n\
-                         31: 14 17 | 1 13\n\
-                         6: 14 14 | 1 14\n\
-                         2: 1 24 | 14 4\n\
-                         0: 8 11\n\
-                         13: 14 3 | 1 12\n\
-                         15: 1 | 14\n\
-                         17: 14 2 | 1 7\n\
-                         23: 25 1 | 22 14\n\
-                         28: 16 1\n\
-                         4: 1 1\n\
-                         20: 14 14 | 1 15\n\
-                         3: 5 14 | 16 1\n\
-                         27: 1 6 | 14 18\n\
-                         14: \"b\"\n\
-                         21: 14 1 | 1 14\n\
-                         25: 1 1 | 1 14\n\
-                         22: 14 14\n\
-                         8: 42\n\
-                         26: 14 22 | 1 20\n\
-                         18: 15 15\n\
-                         7: 14 5 | 1 21\n\
-                         24: 14 1"
-            .parse::<Rules>()
-            .unwrap();
-        let msgs = [
-            "abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa",
-            "bbabbbbaabaabba",
-            "babbbbaabbbbbabbbbbbaabaaabaaa",
-            "aaabbbbbbaaaabaababaabababbabaaabbababababaaa",
-            "bbbbbbbaaaabbbbaaabbabaaa",
-            "bbbababbbbaaaaaaaabbababaaababaabab",
-            "ababaaaaaabaaab",
-            "ababaaaaabbbaba",
-            "baabbaaaabbaaaababbaababb",
-            "abbbbabbbbaaaababbbbbbaaaababb",
-            "aaaaabbaabaaaaababaa",
-            "aaaabbaaaabbaaa",
-            "aaaabbaabbaaaaaaabbbabbbaaabbaabaaa",
-            "babaaabbbaaabaababbaabababaaab",
-            "aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba",
-        ];
+        let (mut rules, msgs) = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(solve(&rules, &msgs), 3);
         rules.0.insert(8, "42 | 42 8".parse::<Rule>().unwrap());
         rules
@@ -1,5 +1,8 @@
 use std::str::FromStr;
 
+use crate::error::{blocks, parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 enum Pixel {
     On,
@@ -80,26 +83,37 @@ struct Tile {
 }
 
 impl FromStr for Tile {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(tile: &str) -> Result<Self, Self::Err> {
         let mut lines = tile.lines();
-        let id = lines
-            .next()
-            .unwrap()
+        let header = lines.next().unwrap_or_default();
+        let id = header
             .strip_prefix("Tile ")
-            .unwrap()
-            .strip_suffix(':')
-            .unwrap()
-            .parse::<u64>()
-            .unwrap();
+            .and_then(|id| id.strip_suffix(':'))
+            .ok_or_else(|| Error::parse(1, 1, header, "Expected a tile header"))?;
+        let id = parse_field(1, header, id)?;
+        let width = tile.lines().nth(1).map_or(0, |row| row.chars().count());
+        if width == 0 {
+            return Err(Error::parse(1, 1, header, "Expected tile pixels"));
+        }
         let pix = lines
-            .map(|line| {
+            .enumerate()
+            .map(|(row, line)| {
+                if line.chars().count() != width {
+                    return Err(Error::parse(
+                        row + 2,
+                        1,
+                        line,
+                        format!("Expected {} pixels", width),
+                    ));
+                }
                 line.chars()
-                    .map(|c| match c {
+                    .enumerate()
+                    .map(|(col, c)| match c {
                         '#' => Ok(On),
                         '.' => Ok(Off),
-                        _ => Err(format!("Invalid char {}", c)),
+                        _ => Err(Error::parse(row + 2, col + 1, line, "Invalid pixel")),
                     })
                     .collect()
             })
@@ -276,7 +290,16 @@ impl Image {
     }
 }
 
-fn solve(tiles: &[Tile]) -> (u64, usize) {
+fn part1(tiles: &[Tile]) -> u64 {
+    let grid = TileGrid::new(tiles);
+    let nw = &grid.0[0][0];
+    let ne = &grid.0[0][grid.size() - 1];
+    let se = &grid.0[grid.size() - 1][grid.size() - 1];
+    let sw = &grid.0[grid.size() - 1][0];
+    nw.id * ne.id * se.id * sw.id
+}
+
+fn part2(tiles: &[Tile]) -> usize {
     let monster = "Tile 0:\n\
                    ..................#.\n\
                    #....##....##....###\n\
@@ -285,28 +308,152 @@ fn solve(tiles: &[Tile]) -> (u64, usize) {
         .unwrap();
     let monsters = monster.variants();
     let grid = TileGrid::new(tiles);
-    let nw = &grid.0[0][0];
-    let ne = &grid.0[0][grid.size() - 1];
-    let se = &grid.0[grid.size() - 1][grid.size() - 1];
-    let sw = &grid.0[grid.size() - 1][0];
     let img = Image::new(&grid);
     let nmonsters: usize = monsters.iter().map(|m| img.find(m)).sum();
-    (
-        nw.id * ne.id * se.id * sw.id,
-        count_on(&img.0) - nmonsters * count_on(&monster.pix),
-    )
+    count_on(&img.0) - nmonsters * count_on(&monster.pix)
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d20.txt");
-    let tiles = input
-        .split("\n\n")
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let (out1, out2) = solve(&tiles);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d20.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "Tile 2311:\n\
+            ..##.#..#.\n\
+            ##..#.....\n\
+            #...##..#.\n\
+            ####.#...#\n\
+            ##.##.###.\n\
+            ##...#.###\n\
+            .#.#.#..##\n\
+            ..#....#..\n\
+            ###...#.#.\n\
+            ..###..###\n\
+            \n\
+            Tile 1951:\n\
+            #.##...##.\n\
+            #.####...#\n\
+            .....#..##\n\
+            #...######\n\
+            .##.#....#\n\
+            .###.#####\n\
+            ###.##.##.\n\
+            .###....#.\n\
+            ..#.#..#.#\n\
+            #...##.#..\n\
+            \n\
+            Tile 1171:\n\
+            ####...##.\n\
+            #..##.#..#\n\
+            ##.#..#.#.\n\
+            .###.####.\n\
+            ..###.####\n\
+            .##....##.\n\
+            .#...####.\n\
+            #.##.####.\n\
+            ####..#...\n\
+            .....##...\n\
+            \n\
+            Tile 1427:\n\
+            ###.##.#..\n\
+            .#..#.##..\n\
+            .#.##.#..#\n\
+            #.#.#.##.#\n\
+            ....#...##\n\
+            ...##..##.\n\
+            ...#.#####\n\
+            .#.####.#.\n\
+            ..#..###.#\n\
+            ..##.#..#.\n\
+            \n\
+            Tile 1489:\n\
+            ##.#.#....\n\
+            ..##...#..\n\
+            .##..##...\n\
+            ..#...#...\n\
+            #####...#.\n\
+            #..#.#.#.#\n\
+            ...#.#.#..\n\
+            ##.#...##.\n\
+            ..##.##.##\n\
+            ###.##.#..\n\
+            \n\
+            Tile 2473:\n\
+            #....####.\n\
+            #..#.##...\n\
+            #.##..#...\n\
+            ######.#.#\n\
+            .#...#.#.#\n\
+            .#########\n\
+            .###.#..#.\n\
+            ########.#\n\
+            ##...##.#.\n\
+            ..###.#.#.\n\
+            \n\
+            Tile 2971:\n\
+            ..#.#....#\n\
+            #...###...\n\
+            #.#.###...\n\
+            ##.##..#..\n\
+            .#####..##\n\
+            .#..####.#\n\
+            #..#.#..#.\n\
+            ..####.###\n\
+            ..#.#.###.\n\
+            ...#.#.#.#\n\
+            \n\
+            Tile 2729:\n\
+            ...#.#.#.#\n\
+            ####.#....\n\
+            ..#.#.....\n\
+            ....#..#.#\n\
+            .##..##.#.\n\
+            .#.####...\n\
+            ####.#.#..\n\
+            ##.####...\n\
+            ##..#.##..\n\
+            #.##...##.\n\
+            \n\
+            Tile 3079:\n\
+            #.#.#####.\n\
+            .#..######\n\
+            ..#.......\n\
+            ######....\n\
+            ####.#..#.\n\
+            .#...#.##.\n\
+            #.#####.##\n\
+            ..#.###...\n\
+            ..#.......\n\
+            ..#.###...",
+    part1: Some("20899048083289"),
+    part2: Some("273"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 20;
+    const TITLE: &'static str = "Jurassic Jigsaw";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Tile>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        blocks(input)
+            .map(|(skip, tile)| tile.parse().map_err(|err: Error| err.offset(skip)))
+            .collect()
+    }
+
+    fn part1(tiles: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(tiles).into())
+    }
+
+    fn part2(tiles: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(tiles).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -360,110 +507,8 @@ mod tests {
 
     #[test]
     fn test01() {
-        let tiles = [
-            "Tile 2311:\n\
-             ..##.#..#.\n\
-             ##..#.....\n\
-             #...##..#.\n\
-             ####.#...#\n\
-             ##.##.###.\n\
-             ##...#.###\n\
-             .#.#.#..##\n\
-             ..#....#..\n\
-             ###...#.#.\n\
-             ..###..###",
-            "Tile 1951:\n\
-             #.##...##.\n\
-             #.####...#\n\
-             .....#..##\n\
-             #...######\n\
-             .##.#....#\n\
-             .###.#####\n\
-             ###.##.##.\n\
-             .###....#.\n\
-             ..#.#..#.#\n\
-             #...##.#..",
-            "Tile 1171:\n\
-             ####...##.\n\
-             #..##.#..#\n\
-             ##.#..#.#.\n\
-             .###.####.\n\
-             ..###.####\n\
-             .##....##.\n\
-             .#...####.\n\
-             #.##.####.\n\
-             ####..#...\n\
-             .....##...",
-            "Tile 1427:\n\
-             ###.##.#..\n\
-             .#..#.##..\n\
-             .#.##.#..#\n\
-             #.#.#.##.#\n\
-             ....#...##\n\
-             ...##..##.\n\
-             ...#.#####\n\
-             .#.####.#.\n\
-             ..#..###.#\n\
-             ..##.#..#.",
-            "Tile 1489:\n\
-             ##.#.#....\n\
-             ..##...#..\n\
-             .##..##...\n\
-             ..#...#...\n\
-             #####...#.\n\
-             #..#.#.#.#\n\
-             ...#.#.#..\n\
-             ##.#...##.\n\
-             ..##.##.##\n\
-             ###.##.#..",
-            "Tile 2473:\n\
-             #....####.\n\
-             #..#.##...\n\
-             #.##..#...\n\
-             ######.#.#\n\
-             .#...#.#.#\n\
-             .#########\n\
-             .###.#..#.\n\
-             ########.#\n\
-             ##...##.#.\n\
-             ..###.#.#.",
-            "Tile 2971:\n\
-             ..#.#....#\n\
-             #...###...\n\
-             #.#.###...\n\
-             ##.##..#..\n\
-             .#####..##\n\
-             .#..####.#\n\
-             #..#.#..#.\n\
-             ..####.###\n\
-             ..#.#.###.\n\
-             ...#.#.#.#",
-            "Tile 2729:\n\
-             ...#.#.#.#\n\
-             ####.#....\n\
-             ..#.#.....\n\
-             ....#..#.#\n\
-             .##..##.#.\n\
-             .#.####...\n\
-             ####.#.#..\n\
-             ##.####...\n\
-             ##..#.##..\n\
-             #.##...##.",
-            "Tile 3079:\n\
-             #.#.#####.\n\
-             .#..######\n\
-             ..#.......\n\
-             ######....\n\
-             ####.#..#.\n\
-             .#...#.##.\n\
-             #.#####.##\n\
-             ..#.###...\n\
-             ..#.......\n\
-             ..#.###...",
-        ]
-        .iter()
-        .map(|tile| tile.parse::<Tile>().unwrap())
-        .collect::<Vec<_>>();
-        assert_eq!(solve(&tiles), (20_899_048_083_289, 273));
+        let tiles = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(part1(&tiles), 20_899_048_083_289);
+        assert_eq!(part2(&tiles), 273);
     }
 }
@@ -2,6 +2,9 @@ use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 use std::str::FromStr;
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 fn extract_singleton<A: Clone>(set: &HashSet<A>) -> Option<&A> {
     if set.len() == 1 {
         set.iter().next()
@@ -31,22 +34,26 @@ struct Foods {
 }
 
 impl FromStr for Foods {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(foods: &str) -> Result<Self, Self::Err> {
         Ok(Self::new(
             &foods
                 .lines()
-                .map(|line| {
-                    let fields = line[..line.len() - 1]
-                        .split(" (contains ")
-                        .collect::<Vec<_>>();
-                    (
-                        fields[0].split_whitespace().collect::<Vec<_>>(),
-                        fields[1].split(", ").collect::<Vec<_>>(),
-                    )
+                .enumerate()
+                .map(|(row, line)| {
+                    let (ingrs, algs) = line
+                        .strip_suffix(')')
+                        .and_then(|line| line.split_once(" (contains "))
+                        .ok_or_else(|| {
+                            Error::parse(row + 1, 1, line, "Expected ingredients and allergens")
+                        })?;
+                    Ok((
+                        ingrs.split_whitespace().collect::<Vec<_>>(),
+                        algs.split(", ").collect::<Vec<_>>(),
+                    ))
                 })
-                .collect::<Vec<_>>(),
+                .collect::<Result<Vec<_>, Error>>()?,
         ))
     }
 }
@@ -105,8 +112,8 @@ impl Foods {
     }
 }
 
-fn solve(foods: &Foods) -> (usize, String) {
-    let cnt = foods
+fn part1(foods: &Foods) -> usize {
+    foods
         .ingredients
         .iter()
         .filter_map(|(ing, cnt)| {
@@ -116,41 +123,63 @@ fn solve(foods: &Foods) -> (usize, String) {
                 .all(|ings| !ings.contains(ing))
                 .then(|| cnt)
         })
-        .sum();
+        .sum()
+}
+
+fn part2(foods: &Foods) -> String {
     let mut bad = foods.mapping.iter().collect::<Vec<_>>();
     bad.sort_by_key(|(alg, _)| *alg);
-    (
-        cnt,
-        bad.drain(..)
-            .map(|(_, ing)| ing)
-            .cloned()
-            .collect::<Vec<_>>()
-            .join(","),
-    )
+    bad.drain(..)
+        .map(|(_, ing)| ing)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d21.txt");
-    let foods = input.parse::<Foods>()?;
-    let (out1, out2) = solve(&foods);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d21.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)\n\
+            trh fvjkl sbzzf mxmxvkd (contains dairy)\n\
+            sqjhc fvjkl (contains soy)\n\
+            sqjhc mxmxvkd sbzzf (contains fish)",
+    part1: Some("5"),
+    part2: Some("mxmxvkd,sqjhc,fvjkl"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 21;
+    const TITLE: &'static str = "Allergen Assessment";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Foods;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(foods: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(foods).into())
+    }
+
+    fn part2(foods: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(foods).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let foods = Foods::new(&[
-            (
-                vec!["mxmxvkd", "kfcds", "sqjhc", "nhms"],
-                vec!["dairy", "fish"],
-            ),
-            (vec!["trh", "fvjkl", "sbzzf", "mxmxvkd"], vec!["dairy"]),
-            (vec!["sqjhc", "fvjkl"], vec!["soy"]),
-            (vec!["sqjhc", "mxmxvkd", "sbzzf"], vec!["fish"]),
-        ]);
-        assert_eq!(solve(&foods), (5, "mxmxvkd,sqjhc,fvjkl".into()));
+        let foods = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(part1(&foods), 5);
+        assert_eq!(part2(&foods), "mxmxvkd,sqjhc,fvjkl");
     }
 }
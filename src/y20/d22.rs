@@ -1,4 +1,8 @@
 use std::collections::{HashSet, VecDeque};
+use std::convert::TryFrom;
+
+use crate::error::{blocks, parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
 
 type Card = usize;
 type Deck = VecDeque<Card>;
@@ -75,37 +79,81 @@ fn solve(deck1: &mut Deck, deck2: &mut Deck, mode: Mode) -> usize {
     })
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d22.txt");
-    let mut decks = input.split("\n\n").map(|deck| {
-        deck.lines()
-            .skip(1)
-            .map(|c| c.parse::<Card>().unwrap())
-            .collect::<Deck>()
-    });
-    let mut deck1 = decks.next().unwrap();
-    let mut deck2 = decks.next().unwrap();
-    let out1 = solve(&mut deck1.clone(), &mut deck2.clone(), Regular);
-    let out2 = solve(&mut deck1, &mut deck2, Recursive);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d22.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "Player 1:\n\
+            9\n\
+            2\n\
+            6\n\
+            3\n\
+            1\n\
+            \n\
+            Player 2:\n\
+            5\n\
+            8\n\
+            4\n\
+            7\n\
+            10",
+    part1: Some("306"),
+    part2: Some("291"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 22;
+    const TITLE: &'static str = "Crab Combat";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (Deck, Deck);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let decks = blocks(input)
+            .map(|(skip, deck)| {
+                deck.lines()
+                    .enumerate()
+                    .skip(1)
+                    .map(|(idx, card)| parse_field(skip + idx + 1, card, card))
+                    .collect::<Result<Deck, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        match <[Deck; 2]>::try_from(decks) {
+            Ok([deck1, deck2]) => Ok((deck1, deck2)),
+            Err(decks) => Err(Error::parse(
+                input.lines().count() + 1,
+                1,
+                "",
+                format!("Expected two decks, got {}", decks.len()),
+            )),
+        }
+    }
+
+    fn part1((deck1, deck2): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(&mut deck1.clone(), &mut deck2.clone(), Regular).into())
+    }
+
+    fn part2((deck1, deck2): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(&mut deck1.clone(), &mut deck2.clone(), Recursive).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let mut deck1 = [9, 2, 6, 3, 1].iter().copied().collect::<Deck>();
-        let mut deck2 = [5, 8, 4, 7, 10].iter().copied().collect::<Deck>();
+        let (mut deck1, mut deck2) = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(solve(&mut deck1, &mut deck2, Regular), 306);
     }
 
     #[test]
     fn test02() {
-        let mut deck1 = [9, 2, 6, 3, 1].iter().copied().collect::<Deck>();
-        let mut deck2 = [5, 8, 4, 7, 10].iter().copied().collect::<Deck>();
+        let (mut deck1, mut deck2) = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(solve(&mut deck1, &mut deck2, Recursive), 291);
     }
 
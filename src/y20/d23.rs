@@ -1,3 +1,6 @@
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 fn list_vec(xs: &[usize]) -> Vec<usize> {
     let mut list = vec![0; xs.len() + 1];
     for pair in xs.windows(2) {
@@ -90,33 +93,64 @@ fn solve(cups: &[usize], extra: Option<usize>, moves: usize) -> usize {
     }
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d23.txt");
-    let cups = input
-        .trim()
-        .chars()
-        .map(|c| c.to_string().parse::<usize>().unwrap())
-        .collect::<Vec<_>>();
-    let out1 = solve(&cups, None, 100);
-    let out2 = solve(&cups, Some(1_000_000), 10_000_000);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d23.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "389125467",
+    part1: Some("67384529"),
+    part2: Some("149245887792"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 23;
+    const TITLE: &'static str = "Crab Cups";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let line = input.trim();
+        if line.is_empty() {
+            return Err(Error::parse(1, 1, line, "Expected cups"));
+        }
+        line.chars()
+            .enumerate()
+            .map(|(col, c)| {
+                c.to_digit(10)
+                    .map(|cup| cup as usize)
+                    .ok_or_else(|| Error::parse(1, col + 1, line, "Invalid cup"))
+            })
+            .collect()
+    }
+
+    fn part1(cups: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(cups, None, 100).into())
+    }
+
+    fn part2(cups: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(cups, Some(1_000_000), 10_000_000).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let cups = [3, 8, 9, 1, 2, 5, 4, 6, 7];
+        let cups = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(solve(&cups, None, 10), 92_658_374);
         assert_eq!(solve(&cups, None, 100), 67_384_529);
     }
 
     #[test]
     fn test02() {
-        let cups = [3, 8, 9, 1, 2, 5, 4, 6, 7];
+        let cups = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(solve(&cups, Some(1_000_000), 10_000_000), 149_245_887_792);
     }
 }
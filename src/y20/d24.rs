@@ -3,6 +3,9 @@ use std::hash::Hash;
 use std::ops::AddAssign;
 use std::str::FromStr;
 
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 fn counter<A, I>(xs: I) -> HashMap<A, usize>
 where
     A: Clone + PartialEq + Eq + Hash,
@@ -157,56 +160,74 @@ impl Tiles {
     }
 }
 
-fn solve(coords: &[Coord]) -> (usize, usize) {
+fn solve(coords: &[Coord], days: usize) -> usize {
     let mut tiles = Tiles::new(coords);
-    let start = tiles.count();
-    for _ in 0..100 {
+    for _ in 0..days {
         tiles.day();
     }
-    (start, tiles.count())
+    tiles.count()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d24.txt");
-    let locs = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let (out1, out2) = solve(&locs);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d24.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "sesenwnenenewseeswwswswwnenewsewsw\n\
+            neeenesenwnwwswnenewnwwsewnenwseswesw\n\
+            seswneswswsenwwnwse\n\
+            nwnwneseeswswnenewneswwnewseswneseene\n\
+            swweswneswnenwsewnwneneseenw\n\
+            eesenwseswswnenwswnwnwsewwnwsene\n\
+            sewnenenenesenwsewnenwwwse\n\
+            wenwwweseeeweswwwnwwe\n\
+            wsweesenenewnwwnwsenewsenwwsesesenwne\n\
+            neeswseenwwswnwswswnw\n\
+            nenwswwsewswnenenewsenwsenwnesesenew\n\
+            enewnwewneswsewnwswenweswnenwsenwsw\n\
+            sweneswneswneneenwnewenewwneswswnese\n\
+            swwesenesewenwneswnwwneseswwne\n\
+            enesenwswwswneneswsenwnewswseenwsese\n\
+            wnwnesenesenenwwnenwsewesewsesesew\n\
+            nenewswnwewswnenesenwnesewesw\n\
+            eneswnwswnwsenenwnwnwwseeswneewsenese\n\
+            neswnwewnwnwseenwseesewsenwsweewe\n\
+            wseweeenwnesenwwwswnew",
+    part1: Some("10"),
+    part2: Some("2208"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 24;
+    const TITLE: &'static str = "Lobby Layout";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Coord>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input)
+    }
+
+    fn part1(locs: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(locs, 0).into())
+    }
+
+    fn part2(locs: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(locs, 100).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let locs = [
-            "sesenwnenenewseeswwswswwnenewsewsw",
-            "neeenesenwnwwswnenewnwwsewnenwseswesw",
-            "seswneswswsenwwnwse",
-            "nwnwneseeswswnenewneswwnewseswneseene",
-            "swweswneswnenwsewnwneneseenw",
-            "eesenwseswswnenwswnwnwsewwnwsene",
-            "sewnenenenesenwsewnenwwwse",
-            "wenwwweseeeweswwwnwwe",
-            "wsweesenenewnwwnwsenewsenwwsesesenwne",
-            "neeswseenwwswnwswswnw",
-            "nenwswwsewswnenenewsenwsenwnesesenew",
-            "enewnwewneswsewnwswenweswnenwsenwsw",
-            "sweneswneswneneenwnewenewwneswswnese",
-            "swwesenesewenwneswnwwneseswwne",
-            "enesenwswwswneneswsenwnewswseenwsese",
-            "wnwnesenesenenwwnenwsewesewsesesew",
-            "nenewswnwewswnenesenwnesewesw",
-            "eneswnwswnwsenenwnwnwwseeswneewsenese",
-            "neswnwewnwnwseenwseesewsenwsweewe",
-            "wseweeenwnesenwwwswnew",
-        ]
-        .iter()
-        .map(|loc| loc.parse::<Coord>().unwrap())
-        .collect::<Vec<_>>();
-        assert_eq!(solve(&locs), (10, 2208));
+        let locs = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(&locs, 0), 10);
+        assert_eq!(solve(&locs, 100), 2208);
     }
 }
@@ -1,3 +1,6 @@
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 // sub ^ loop_sz mod 20201227
 fn trans(sub: u64, loop_sz: u64) -> u64 {
     (0..loop_sz).fold(1, |acc, _| acc * sub % 2020_1227)
@@ -21,18 +24,48 @@ fn solve(card_pub: u64, door_pub: u64) -> u64 {
     trans(card_pub, door_loop)
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d25.txt");
-    let pubs = input
-        .lines()
-        .map(|x| x.parse::<u64>().unwrap())
-        .collect::<Vec<_>>();
-    let out1 = solve(pubs[0], pubs[1]);
-    let out2 = "";
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d25.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "5764801\n\
+            17807724",
+    part1: Some("14897079"),
+    part2: None,
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 25;
+    const TITLE: &'static str = "Combo Breaker";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (u64, u64);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        match parse_lines(input)?[..] {
+            [card_pub, door_pub] => Ok((card_pub, door_pub)),
+            ref pubs => Err(Error::parse(
+                pubs.len().min(2) + 1,
+                1,
+                input.lines().nth(2).unwrap_or_default(),
+                "Expected two public keys",
+            )),
+        }
+    }
+
+    fn part1((card_pub, door_pub): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(*card_pub, *door_pub).into())
+    }
+
+    fn part2(_: &Self::Input) -> Result<Answer, Error> {
+        Ok("".into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -47,6 +80,7 @@ mod tests {
 
     #[test]
     fn test01() {
-        assert_eq!(solve(5_764_801, 17_807_724), 14_897_079);
+        let (card_pub, door_pub) = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(solve(card_pub, door_pub), 14_897_079);
     }
 }
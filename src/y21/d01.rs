@@ -1,3 +1,6 @@
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 fn part1(depths: &[u64]) -> usize {
     depths.windows(2).filter(|ds| ds[0] < ds[1]).count()
 }
@@ -13,31 +16,61 @@ fn part2(depths: &[u64]) -> usize {
         .count()
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d01.txt");
-    let depths = input
-        .lines()
-        .map(|d| d.parse().unwrap())
-        .collect::<Vec<_>>();
-    let out1 = part1(&depths);
-    let out2 = part2(&depths);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d01.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "199\n\
+            200\n\
+            208\n\
+            210\n\
+            200\n\
+            207\n\
+            240\n\
+            269\n\
+            260\n\
+            263",
+    part1: Some("7"),
+    part2: Some("5"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input)
+    }
+
+    fn part1(depths: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(depths).into())
+    }
+
+    fn part2(depths: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(depths).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let depths = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&depths), 7);
     }
 
     #[test]
     fn test02() {
-        let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let depths = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part2(&depths), 5);
     }
 }
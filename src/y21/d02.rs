@@ -1,5 +1,8 @@
 use std::str::FromStr;
 
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone, Copy)]
 enum Dir {
     Forward(i64),
@@ -100,30 +103,57 @@ fn part2(dirs: &[Dir]) -> i64 {
     pos.horz * pos.depth
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d02.txt");
-    let dirs = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = part1(&dirs);
-    let out2 = part2(&dirs);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d02.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "forward 5\n\
+            down 5\n\
+            forward 8\n\
+            up 3\n\
+            down 8\n\
+            forward 2",
+    part1: Some("150"),
+    part2: Some("900"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Dive!";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Dir>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input)
+    }
+
+    fn part1(dirs: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(dirs).into())
+    }
+
+    fn part2(dirs: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(dirs).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let dirs = [Forward(5), Down(5), Forward(8), Up(3), Down(8), Forward(2)];
+        let dirs = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&dirs), 150);
     }
 
     #[test]
     fn test02() {
-        let dirs = [Forward(5), Down(5), Forward(8), Up(3), Down(8), Forward(2)];
+        let dirs = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part2(&dirs), 900);
     }
 }
@@ -1,6 +1,9 @@
 use std::ops::Add;
 use std::str::FromStr;
 
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone)]
 struct Bin(Vec<u64>);
 
@@ -8,9 +11,15 @@ impl FromStr for Bin {
     type Err = String;
 
     fn from_str(bin: &str) -> Result<Self, Self::Err> {
-        Ok(Self(
-            bin.chars().map(|c| c.to_digit(2).unwrap().into()).collect(),
-        ))
+        let bits = bin
+            .chars()
+            .map(|c| {
+                c.to_digit(2)
+                    .map(u64::from)
+                    .ok_or_else(|| format!("Invalid bit {}", c))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self(bits))
     }
 }
 
@@ -88,56 +97,63 @@ fn part2(bins: &[Bin]) -> u64 {
     o2.to_u64() * co2.to_u64()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d03.txt");
-    let bins = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = part1(&bins);
-    let out2 = part2(&bins);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d03.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "00100\n\
+            11110\n\
+            10110\n\
+            10111\n\
+            10101\n\
+            01111\n\
+            00111\n\
+            11100\n\
+            10000\n\
+            11001\n\
+            00010\n\
+            01010",
+    part1: Some("198"),
+    part2: Some("230"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Bin>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input)
+    }
+
+    fn part1(bins: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(bins).into())
+    }
+
+    fn part2(bins: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(bins).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let bins = [
-            Bin(vec![0, 0, 1, 0, 0]),
-            Bin(vec![1, 1, 1, 1, 0]),
-            Bin(vec![1, 0, 1, 1, 0]),
-            Bin(vec![1, 0, 1, 1, 1]),
-            Bin(vec![1, 0, 1, 0, 1]),
-            Bin(vec![0, 1, 1, 1, 1]),
-            Bin(vec![0, 0, 1, 1, 1]),
-            Bin(vec![1, 1, 1, 0, 0]),
-            Bin(vec![1, 0, 0, 0, 0]),
-            Bin(vec![1, 1, 0, 0, 1]),
-            Bin(vec![0, 0, 0, 1, 0]),
-            Bin(vec![0, 1, 0, 1, 0]),
-        ];
+        let bins = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&bins), 198);
     }
 
     #[test]
     fn test02() {
-        let bins = [
-            Bin(vec![0, 0, 1, 0, 0]),
-            Bin(vec![1, 1, 1, 1, 0]),
-            Bin(vec![1, 0, 1, 1, 0]),
-            Bin(vec![1, 0, 1, 1, 1]),
-            Bin(vec![1, 0, 1, 0, 1]),
-            Bin(vec![0, 1, 1, 1, 1]),
-            Bin(vec![0, 0, 1, 1, 1]),
-            Bin(vec![1, 1, 1, 0, 0]),
-            Bin(vec![1, 0, 0, 0, 0]),
-            Bin(vec![1, 1, 0, 0, 1]),
-            Bin(vec![0, 0, 0, 1, 0]),
-            Bin(vec![0, 1, 0, 1, 0]),
-        ];
+        let bins = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part2(&bins), 230);
     }
 }
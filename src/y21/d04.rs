@@ -3,6 +3,9 @@ use std::str::FromStr;
 
 use itertools::Itertools;
 
+use crate::error::{blocks, parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone, Copy)]
 struct Pos {
     row: usize,
@@ -18,20 +21,25 @@ struct Board {
 }
 
 impl FromStr for Board {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(board: &str) -> Result<Self, Self::Err> {
-        let unmarked = board
-            .lines()
-            .enumerate()
-            .flat_map(|(row, line)| {
-                line.split_whitespace()
-                    .enumerate()
-                    .map(move |(col, val)| (val.parse().unwrap(), Pos { row, col }))
-            })
-            .collect::<HashMap<_, _>>();
-        let width = unmarked.values().map(|pos| pos.col + 1).max().unwrap();
-        let height = unmarked.values().map(|pos| pos.row + 1).max().unwrap();
+        let mut unmarked = HashMap::new();
+        for (row, line) in board.lines().enumerate() {
+            for (col, val) in line.split_whitespace().enumerate() {
+                unmarked.insert(parse_field(row + 1, line, val)?, Pos { row, col });
+            }
+        }
+        let width = unmarked.values().map(|pos| pos.col + 1).max();
+        let height = unmarked.values().map(|pos| pos.row + 1).max();
+        let (width, height) = width.zip(height).ok_or_else(|| {
+            Error::parse(
+                1,
+                1,
+                board.lines().next().unwrap_or_default(),
+                "Expected a board",
+            )
+        })?;
         Ok(Self {
             unmarked,
             marked: HashMap::new(),
@@ -86,83 +94,83 @@ fn part2(draws: &[u8], mut boards: Vec<Board>) -> Option<u64> {
     None
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d04.txt");
-    let (draws, boards) = input.split_once("\n\n").unwrap();
-    let draws = draws
-        .split(',')
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|_| "Failed to parse draw")?;
-    let boards = boards
-        .split("\n\n")
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = part1(&draws, boards.clone()).ok_or("No winning board")?;
-    let out2 = part2(&draws, boards).ok_or("No winning board")?;
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d04.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1\n\
+            \n\
+            22 13 17 11  0\n\
+            8  2 23  4 24\n\
+            21  9 14 16  7\n\
+            6 10  3 18  5\n\
+            1 12 20 15 19\n\
+            \n\
+            3 15  0  2 22\n\
+            9 18 13 17  5\n\
+            19  8  7 25 23\n\
+            20 11 10 24  4\n\
+            14 21 16 12  6\n\
+            \n\
+            14 21 17 24  4\n\
+            10 16 15  9 19\n\
+            18  8 23 26 20\n\
+            22 11 13  6  5\n\
+            2  0 12  3  7",
+    part1: Some("4512"),
+    part2: Some("1924"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 4;
+    const TITLE: &'static str = "Giant Squid";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (Vec<u8>, Vec<Board>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut blocks = blocks(input);
+        let (_, draws) = blocks.next().unwrap_or_default();
+        let draws = draws
+            .split(',')
+            .map(|draw| parse_field(1, draws, draw))
+            .collect::<Result<Vec<_>, _>>()?;
+        let boards = blocks
+            .map(|(skip, board)| board.parse().map_err(|err: Error| err.offset(skip)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((draws, boards))
+    }
+
+    fn part1((draws, boards): &Self::Input) -> Result<Answer, Error> {
+        part1(draws, boards.clone())
+            .map(Answer::from)
+            .ok_or_else(|| Error::no_solution("No winning board"))
+    }
+
+    fn part2((draws, boards): &Self::Input) -> Result<Answer, Error> {
+        part2(draws, boards.clone())
+            .map(Answer::from)
+            .ok_or_else(|| Error::no_solution("No winning board"))
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let draws = [
-            7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8, 19,
-            3, 26, 1,
-        ];
-        let boards = [
-            "22 13 17 11  0\n\
-              8  2 23  4 24\n\
-             21  9 14 16  7\n\
-              6 10  3 18  5\n\
-              1 12 20 15 19",
-            " 3 15  0  2 22\n\
-              9 18 13 17  5\n\
-             19  8  7 25 23\n\
-             20 11 10 24  4\n\
-             14 21 16 12  6",
-            "14 21 17 24  4\n\
-             10 16 15  9 19\n\
-             18  8 23 26 20\n\
-             22 11 13  6  5\n\
-              2  0 12  3  7",
-        ]
-        .iter()
-        .map(|lines| lines.parse().unwrap())
-        .collect::<Vec<_>>();
+        let (draws, boards) = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&draws, boards), Some(4512));
     }
 
     #[test]
     fn test02() {
-        let draws = [
-            7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8, 19,
-            3, 26, 1,
-        ];
-        let boards = [
-            "22 13 17 11  0\n\
-              8  2 23  4 24\n\
-             21  9 14 16  7\n\
-              6 10  3 18  5\n\
-              1 12 20 15 19",
-            " 3 15  0  2 22\n\
-              9 18 13 17  5\n\
-             19  8  7 25 23\n\
-             20 11 10 24  4\n\
-             14 21 16 12  6",
-            "14 21 17 24  4\n\
-             10 16 15  9 19\n\
-             18  8 23 26 20\n\
-             22 11 13  6  5\n\
-              2  0 12  3  7",
-        ]
-        .iter()
-        .map(|lines| lines.parse().unwrap())
-        .collect::<Vec<_>>();
+        let (draws, boards) = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part2(&draws, boards), Some(1924));
     }
 }
@@ -3,6 +3,9 @@ use std::str::FromStr;
 
 use itertools::Itertools;
 
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Point {
     x: u64,
@@ -13,20 +16,13 @@ impl FromStr for Point {
     type Err = String;
 
     fn from_str(pt: &str) -> Result<Self, Self::Err> {
-        let mut pts = pt.split(',').map(str::parse);
-        let x = pts.next().unwrap().map_err(|_| "Invalid point x")?;
-        let y = pts.next().unwrap().map_err(|_| "Invalid point y")?;
+        let (x, y) = pt.split_once(',').ok_or("Expected a point")?;
+        let x = x.parse().map_err(|_| "Invalid point x")?;
+        let y = y.parse().map_err(|_| "Invalid point y")?;
         Ok(Self { x, y })
     }
 }
 
-impl Point {
-    #[cfg(test)]
-    const fn new(x: u64, y: u64) -> Self {
-        Self { x, y }
-    }
-}
-
 #[derive(Debug, Clone, Copy)]
 struct Line {
     start: Point,
@@ -44,22 +40,15 @@ impl FromStr for Line {
     type Err = String;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
-        let mut pts = line.split(" -> ").map(str::parse);
-        let start = pts.next().unwrap()?;
-        let end = pts.next().unwrap()?;
-        Ok(Self { start, end })
+        let (start, end) = line.split_once(" -> ").ok_or("Expected a line")?;
+        Ok(Self {
+            start: start.parse()?,
+            end: end.parse()?,
+        })
     }
 }
 
 impl Line {
-    #[cfg(test)]
-    const fn new_xy(x1: u64, y1: u64, x2: u64, y2: u64) -> Self {
-        Self {
-            start: Point::new(x1, y1),
-            end: Point::new(x2, y2),
-        }
-    }
-
     const fn iter(self) -> LineIter {
         LineIter {
             cur: self.start,
@@ -134,52 +123,61 @@ fn part2(lines: &[Line]) -> usize {
     count_overlap(lines)
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d05.txt");
-    let lines = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = part1(&lines);
-    let out2 = part2(&lines);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d05.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "0,9 -> 5,9\n\
+            8,0 -> 0,8\n\
+            9,4 -> 3,4\n\
+            2,2 -> 2,1\n\
+            7,0 -> 7,4\n\
+            6,4 -> 2,0\n\
+            0,9 -> 2,9\n\
+            3,4 -> 1,4\n\
+            0,0 -> 8,8\n\
+            5,5 -> 8,2",
+    part1: Some("5"),
+    part2: Some("12"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(lines).into())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(lines).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let lines = [
-            Line::new_xy(0, 9, 5, 9),
-            Line::new_xy(8, 0, 0, 8),
-            Line::new_xy(9, 4, 3, 4),
-            Line::new_xy(2, 2, 2, 1),
-            Line::new_xy(7, 0, 7, 4),
-            Line::new_xy(6, 4, 2, 0),
-            Line::new_xy(0, 9, 2, 9),
-            Line::new_xy(3, 4, 1, 4),
-            Line::new_xy(0, 0, 8, 8),
-            Line::new_xy(5, 5, 8, 2),
-        ];
+        let lines = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&lines), 5);
     }
 
     #[test]
     fn test02() {
-        let lines = [
-            Line::new_xy(0, 9, 5, 9),
-            Line::new_xy(8, 0, 0, 8),
-            Line::new_xy(9, 4, 3, 4),
-            Line::new_xy(2, 2, 2, 1),
-            Line::new_xy(7, 0, 7, 4),
-            Line::new_xy(6, 4, 2, 0),
-            Line::new_xy(0, 9, 2, 9),
-            Line::new_xy(3, 4, 1, 4),
-            Line::new_xy(0, 0, 8, 8),
-            Line::new_xy(5, 5, 8, 2),
-        ];
+        let lines = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part2(&lines), 12);
     }
 }
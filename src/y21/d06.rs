@@ -1,5 +1,8 @@
 use std::convert::TryInto;
 
+use crate::error::{column, parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 type Timer = usize;
 
 const MAX_TIMER: Timer = 6;
@@ -46,35 +49,61 @@ fn part1(mut fish: Fish, days: u64) -> usize {
     fish.count()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d06.txt");
-    let fish = Fish::new(
-        &input
-            .trim()
+pub const INPUT: &str = include_str!("input/d06.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "3,4,3,1,2",
+    part1: Some("5934"),
+    part2: Some("26984457539"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 6;
+    const TITLE: &'static str = "Lanternfish";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Fish;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let line = input.trim();
+        let timers = line
             .split(',')
-            .map(str::parse)
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|_| "Invalid timer")?,
-    );
-    let out1 = part1(fish.clone(), 80);
-    let out2 = part1(fish, 256);
-    Ok(format!("{} {}", out1, out2))
+            .map(|timer| match parse_field(1, line, timer)? {
+                t if t <= NEW_TIMER => Ok(t),
+                _ => Err(Error::parse(1, column(line, timer), line, "Invalid timer")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Fish::new(&timers))
+    }
+
+    fn part1(fish: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(fish.clone(), 80).into())
+    }
+
+    fn part2(fish: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(fish.clone(), 256).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let fish = Fish::new(&[3, 4, 3, 1, 2]);
+        let fish = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(fish.clone(), 18), 26);
         assert_eq!(part1(fish, 80), 5934);
     }
 
     #[test]
     fn test02() {
-        let fish = Fish::new(&[3, 4, 3, 1, 2]);
+        let fish = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(fish, 256), 26_984_457_539);
     }
 }
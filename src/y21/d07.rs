@@ -1,3 +1,6 @@
+use crate::error::{parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 fn median(pos: &mut [u64]) -> u64 {
     pos.sort_unstable();
     pos[pos.len() / 2]
@@ -25,32 +28,55 @@ fn part2(pos: &[u64]) -> u64 {
         .unwrap()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d07.txt");
-    let pos = input
-        .trim()
-        .split(',')
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|_| "Invalid position")?;
-    let out1 = part1(&mut pos.clone());
-    let out2 = part2(&pos);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d07.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "16,1,2,0,4,2,7,1,2,14",
+    part1: Some("37"),
+    part2: Some("168"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let line = input.trim();
+        line.split(',')
+            .map(|pos| parse_field(1, line, pos))
+            .collect()
+    }
+
+    fn part1(pos: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(&mut pos.clone()).into())
+    }
+
+    fn part2(pos: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(pos).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let mut pos = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        let mut pos = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&mut pos), 37);
     }
 
     #[test]
     fn test02() {
-        let pos = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        let pos = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part2(&pos), 168);
     }
 }
@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
+use crate::error::{parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 enum Seg {
     A,
@@ -234,168 +237,91 @@ fn part2(runs: &[(Vec<Digit>, Vec<Digit>)]) -> u64 {
     runs.iter().map(|(pats, out)| decode(pats, out)).sum()
 }
 
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d08.txt");
-    let runs = input
-        .lines()
-        .map(|line| -> Result<(Vec<Digit>, Vec<Digit>), String> {
-            let mut line = line.split(" | ");
-            let pats = line.next().unwrap();
-            let out = line.next().unwrap();
-            Ok((
-                pats.split_whitespace()
-                    .map(str::parse)
-                    .collect::<Result<_, _>>()?,
-                out.split_whitespace()
-                    .map(str::parse)
-                    .collect::<Result<_, _>>()?,
-            ))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = part1(&runs);
-    let out2 = part2(&runs);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d08.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe\n\
+                edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc\n\
+                fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg\n\
+                fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb\n\
+                aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea\n\
+                fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb\n\
+                dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe\n\
+                bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef\n\
+                egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb\n\
+                gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
+        part1: Some("26"),
+        part2: Some("61229"),
+    },
+    Example {
+        input: "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf",
+        part1: None,
+        part2: Some("5353"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 8;
+    const TITLE: &'static str = "Seven Segment Search";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<(Vec<Digit>, Vec<Digit>)>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                let (pats, out) = line.split_once(" | ").ok_or_else(|| {
+                    Error::parse(row + 1, 1, line, "Expected patterns and output")
+                })?;
+                let digits = |digits: &str| {
+                    digits
+                        .split_whitespace()
+                        .map(|digit| parse_field(row + 1, line, digit))
+                        .collect::<Result<_, _>>()
+                };
+                Ok((digits(pats)?, digits(out)?))
+            })
+            .collect()
+    }
+
+    fn part1(runs: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(runs).into())
+    }
+
+    fn part2(runs: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(runs).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let runs = [
-            (
-                "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb",
-                "fdgacbe cefdb cefbgd gcbe",
-            ),
-            (
-                "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec",
-                "fcgedb cgb dgebacf gc",
-            ),
-            (
-                "fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef",
-                "cg cg fdcagb cbg",
-            ),
-            (
-                "fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega",
-                "efabcd cedba gadfec cb",
-            ),
-            (
-                "aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga",
-                "gecf egdcabf bgf bfgea",
-            ),
-            (
-                "fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf",
-                "gebdcfa ecba ca fadegcb",
-            ),
-            (
-                "dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf",
-                "cefg dcbef fcge gbcadfe",
-            ),
-            (
-                "bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd",
-                "ed bcgafe cdgba cbgef",
-            ),
-            (
-                "egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg",
-                "gbdfcae bgc cg cgb",
-            ),
-            (
-                "gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc",
-                "fgae cfgab fg bagce",
-            ),
-        ];
-        let runs = runs
-            .iter()
-            .map(|(pats, out)| -> Result<(Vec<Digit>, Vec<Digit>), String> {
-                Ok((
-                    pats.split_whitespace()
-                        .map(str::parse)
-                        .collect::<Result<_, _>>()?,
-                    out.split_whitespace()
-                        .map(str::parse)
-                        .collect::<Result<_, _>>()?,
-                ))
-            })
-            .collect::<Result<Vec<_>, _>>()
-            .unwrap();
+        let runs = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&runs), 26);
     }
 
     #[test]
     fn test_decode() {
-        let pats = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab"
-            .split_whitespace()
-            .map(str::parse)
-            .collect::<Result<Vec<_>, _>>()
-            .unwrap();
-        let out = "cdfeb fcadb cdfeb cdbaf"
-            .split_whitespace()
-            .map(str::parse)
-            .collect::<Result<Vec<_>, _>>()
-            .unwrap();
-        assert_eq!(decode(&pats, &out), 5353);
+        let runs = Solver::parse(EXAMPLES[1].input).unwrap();
+        let (pats, out) = &runs[0];
+        assert_eq!(decode(pats, out), 5353);
     }
 
     #[ignore]
     #[test]
     fn test02() {
-        let runs = [
-            (
-                "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb",
-                "fdgacbe cefdb cefbgd gcbe",
-            ),
-            (
-                "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec",
-                "fcgedb cgb dgebacf gc",
-            ),
-            (
-                "fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef",
-                "cg cg fdcagb cbg",
-            ),
-            (
-                "fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega",
-                "efabcd cedba gadfec cb",
-            ),
-            (
-                "aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga",
-                "gecf egdcabf bgf bfgea",
-            ),
-            (
-                "fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf",
-                "gebdcfa ecba ca fadegcb",
-            ),
-            (
-                "dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf",
-                "cefg dcbef fcge gbcadfe",
-            ),
-            (
-                "bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd",
-                "ed bcgafe cdgba cbgef",
-            ),
-            (
-                "egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg",
-                "gbdfcae bgc cg cgb",
-            ),
-            (
-                "gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc",
-                "fgae cfgab fg bagce",
-            ),
-        ];
-        let runs = runs
-            .iter()
-            .map(|(pats, out)| -> Result<(Vec<Digit>, Vec<Digit>), String> {
-                Ok((
-                    pats.split_whitespace()
-                        .map(str::parse)
-                        .collect::<Result<_, _>>()?,
-                    out.split_whitespace()
-                        .map(str::parse)
-                        .collect::<Result<_, _>>()?,
-                ))
-            })
-            .collect::<Result<Vec<_>, _>>()
-            .unwrap();
+        let runs = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(
             runs.iter()
                 .map(|(pats, out)| decode(pats, out))
@@ -2,6 +2,9 @@ use std::collections::HashSet;
 
 use itertools::Itertools;
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 fn neighbors(r: usize, c: usize) -> Vec<(usize, usize)> {
     [
         r.checked_sub(1).zip(Some(c)),
@@ -72,48 +75,69 @@ fn part2(heights: &[Vec<u32>]) -> usize {
         .product()
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d09.txt");
-    let heights = input
-        .lines()
-        .map(|line| {
-            line.trim()
-                .chars()
-                .map(|c| c.to_digit(10).unwrap())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-    let out1 = part1(&heights);
-    let out2 = part2(&heights);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d09.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "2199943210\n\
+            3987894921\n\
+            9856789892\n\
+            8767896789\n\
+            9899965678",
+    part1: Some("15"),
+    part2: Some("1134"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 9;
+    const TITLE: &'static str = "Smoke Basin";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                let line = line.trim();
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| {
+                        c.to_digit(10)
+                            .ok_or_else(|| Error::parse(row + 1, col + 1, line, "Invalid height"))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(heights: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(heights).into())
+    }
+
+    fn part2(heights: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(heights).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let heights = [
-            vec![2, 1, 9, 9, 9, 4, 3, 2, 1, 0],
-            vec![3, 9, 8, 7, 8, 9, 4, 9, 2, 1],
-            vec![9, 8, 5, 6, 7, 8, 9, 8, 9, 2],
-            vec![8, 7, 6, 7, 8, 9, 6, 7, 8, 9],
-            vec![9, 8, 9, 9, 9, 6, 5, 6, 7, 8],
-        ];
+        let heights = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&heights), 15);
     }
 
     #[test]
     fn test02() {
-        let heights = [
-            vec![2, 1, 9, 9, 9, 4, 3, 2, 1, 0],
-            vec![3, 9, 8, 7, 8, 9, 4, 9, 2, 1],
-            vec![9, 8, 5, 6, 7, 8, 9, 8, 9, 2],
-            vec![8, 7, 6, 7, 8, 9, 6, 7, 8, 9],
-            vec![9, 8, 9, 9, 9, 6, 5, 6, 7, 8],
-        ];
+        let heights = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part2(&heights), 1134);
     }
 }
@@ -1,5 +1,8 @@
 use std::convert::TryFrom;
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Bracket {
     Paren,
@@ -165,23 +168,61 @@ fn part2(nav: &[Vec<Token>]) -> u64 {
     scores[scores.len() / 2]
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d10.txt");
-    let nav = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(Token::try_from)
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|c| format!("Invalid char: {}", c))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = part1(&nav);
-    let out2 = part2(&nav);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d10.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "[({(<(())[]>[[{[]{<()<>>\n\
+            [(()[<>])]({[<{<<[]>>(\n\
+            {([(<{}[<>[]}>{[]{[(<()>\n\
+            (((({<>}<{<{<>}{[]{[]{}\n\
+            [[<[([]))<([[{}[[()]]]\n\
+            [{[{({}]{}}([{[{{{}}([]\n\
+            {<[[]]>}<{[{[{[]{()[[[]\n\
+            [<(<(<(<{}))><([]([]()\n\
+            <{([([[(<>()){}]>(<<{{\n\
+            <{([{{}}[<[[[<>{}]]]>[]]",
+    part1: Some("26397"),
+    part2: Some("288957"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 10;
+    const TITLE: &'static str = "Syntax Scoring";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Vec<Token>>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| {
+                        Token::try_from(c).map_err(|c| {
+                            Error::parse(row + 1, col + 1, line, format!("Invalid char: {}", c))
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(nav: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(nav).into())
+    }
+
+    fn part2(nav: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(nav).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -276,49 +317,13 @@ mod tests {
 
     #[test]
     fn test01() {
-        let nav = [
-            "[({(<(())[]>[[{[]{<()<>>",
-            "[(()[<>])]({[<{<<[]>>(",
-            "{([(<{}[<>[]}>{[]{[(<()>",
-            "(((({<>}<{<{<>}{[]{[]{}",
-            "[[<[([]))<([[{}[[()]]]",
-            "[{[{({}]{}}([{[{{{}}([]",
-            "{<[[]]>}<{[{[{[]{()[[[]",
-            "[<(<(<(<{}))><([]([]()",
-            "<{([([[(<>()){}]>(<<{{",
-            "<{([{{}}[<[[[<>{}]]]>[]]",
-        ]
-        .iter()
-        .map(|line| {
-            line.chars()
-                .map(|c| Token::try_from(c).unwrap())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
+        let nav = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&nav), 26397);
     }
 
     #[test]
     fn test02() {
-        let nav = [
-            "[({(<(())[]>[[{[]{<()<>>",
-            "[(()[<>])]({[<{<<[]>>(",
-            "{([(<{}[<>[]}>{[]{[(<()>",
-            "(((({<>}<{<{<>}{[]{[]{}",
-            "[[<[([]))<([[{}[[()]]]",
-            "[{[{({}]{}}([{[{{{}}([]",
-            "{<[[]]>}<{[{[{[]{()[[[]",
-            "[<(<(<(<{}))><([]([]()",
-            "<{([([[(<>()){}]>(<<{{",
-            "<{([{{}}[<[[[<>{}]]]>[]]",
-        ]
-        .iter()
-        .map(|line| {
-            line.chars()
-                .map(|c| Token::try_from(c).unwrap())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
+        let nav = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part2(&nav), 288_957);
     }
 }
@@ -1,5 +1,8 @@
 use std::str::FromStr;
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 fn neighbors(r: usize, c: usize, w: usize, h: usize) -> Vec<(usize, usize)> {
     let up = r.checked_sub(1);
     let down = (r + 1 < h).then(|| r + 1);
@@ -44,24 +47,39 @@ struct Grid {
 }
 
 impl FromStr for Grid {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(grid: &str) -> Result<Self, Self::Err> {
+        let first = grid.lines().next().unwrap_or_default();
+        let cols = first.chars().count();
+        if cols == 0 {
+            return Err(Error::parse(1, 1, first, "Expected octopi"));
+        }
         let octopi = grid
             .lines()
-            .map(|line| {
+            .enumerate()
+            .map(|(row, line)| {
+                if line.chars().count() != cols {
+                    return Err(Error::parse(
+                        row + 1,
+                        1,
+                        line,
+                        format!("Expected {} octopi", cols),
+                    ));
+                }
                 line.chars()
-                    .map(|c| {
+                    .enumerate()
+                    .map(|(col, c)| {
                         c.to_digit(10)
                             .map(Octopus::new)
-                            .ok_or_else(|| format!("Invalid char: {}", c))
+                            .ok_or_else(|| Error::parse(row + 1, col + 1, line, "Invalid energy"))
                     })
                     .collect::<Result<Vec<_>, _>>()
             })
             .collect::<Result<Vec<_>, _>>()?;
         Ok(Self {
-            width: octopi.len(),
-            height: octopi[0].len(),
+            width: cols,
+            height: octopi.len(),
             octopi,
         })
     }
@@ -117,51 +135,69 @@ fn part2(mut grid: Grid) -> usize {
         .unwrap()
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d11.txt");
-    let grid = input.parse::<Grid>()?;
-    let out1 = part1(grid.clone(), 100);
-    let out2 = part2(grid);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d11.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "5483143223\n\
+            2745854711\n\
+            5264556173\n\
+            6141336146\n\
+            6357385478\n\
+            4167524645\n\
+            2176841721\n\
+            6882881134\n\
+            4846848554\n\
+            5283751526",
+    part1: Some("1656"),
+    part2: Some("195"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(grid.clone(), 100).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(grid.clone()).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let grid = "5483143223\n\
-                    2745854711\n\
-                    5264556173\n\
-                    6141336146\n\
-                    6357385478\n\
-                    4167524645\n\
-                    2176841721\n\
-                    6882881134\n\
-                    4846848554\n\
-                    5283751526"
-            .parse::<Grid>()
-            .unwrap();
+        let grid = EXAMPLES[0].input.parse::<Grid>().unwrap();
         assert_eq!(part1(grid.clone(), 10), 204);
         assert_eq!(part1(grid, 100), 1656);
     }
 
     #[test]
     fn test02() {
-        let grid = "5483143223\n\
-                    2745854711\n\
-                    5264556173\n\
-                    6141336146\n\
-                    6357385478\n\
-                    4167524645\n\
-                    2176841721\n\
-                    6882881134\n\
-                    4846848554\n\
-                    5283751526"
-            .parse::<Grid>()
-            .unwrap();
+        let grid = EXAMPLES[0].input.parse::<Grid>().unwrap();
         assert_eq!(part2(grid), 195);
     }
+
+    #[test]
+    fn test_not_square() {
+        let grid = "9000\n0000\n0009".parse::<Grid>().unwrap();
+        assert_eq!((grid.width, grid.height), (4, 3));
+        assert_eq!(part1(grid, 1), 2);
+    }
 }
@@ -2,6 +2,9 @@ use std::collections::{HashMap, HashSet};
 
 use multimap::MultiMap;
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Cave<'c> {
     Start,
@@ -15,7 +18,7 @@ impl<'c> Cave<'c> {
         match cave {
             "start" => Self::Start,
             "end" => Self::End,
-            _ if cave.chars().next().unwrap().is_uppercase() => Self::Big(cave),
+            _ if cave.chars().all(char::is_uppercase) => Self::Big(cave),
             _ => Self::Small(cave),
         }
     }
@@ -35,22 +38,18 @@ struct Caves<'c> {
 }
 
 impl<'c> Caves<'c> {
-    fn new(caves: &'c str) -> Self {
+    fn new(caves: &'c str) -> Result<Self, Error> {
         let mut map = MultiMap::new();
-        caves
-            .lines()
-            .map(|line| {
-                let mut caves = line.split('-');
-                (
-                    Cave::new(caves.next().unwrap()),
-                    Cave::new(caves.next().unwrap()),
-                )
-            })
-            .for_each(|(x, y)| {
-                map.insert(x, y);
-                map.insert(y, x);
-            });
-        Self { caves: map }
+        for (row, line) in caves.lines().enumerate() {
+            let (x, y) = line
+                .split_once('-')
+                .filter(|(x, y)| !x.is_empty() && !y.is_empty())
+                .ok_or_else(|| Error::parse(row + 1, 1, line, "Expected a path"))?;
+            let (x, y) = (Cave::new(x), Cave::new(y));
+            map.insert(x, y);
+            map.insert(y, x);
+        }
+        Ok(Self { caves: map })
     }
 
     fn navigate(&self, start: Cave<'c>, visited: &mut HashSet<Cave<'c>>) -> u64 {
@@ -119,116 +118,109 @@ fn part2<'c>(caves: &Caves<'c>) -> u64 {
     caves.navigate_twice(Cave::Start, &mut visited, false)
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d12.txt");
-    let caves = Caves::new(input);
-    let out1 = part1(&caves);
-    let out2 = part2(&caves);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d12.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "start-A\n\
+                start-b\n\
+                A-c\n\
+                A-b\n\
+                b-d\n\
+                A-end\n\
+                b-end",
+        part1: Some("10"),
+        part2: Some("36"),
+    },
+    Example {
+        input: "dc-end\n\
+                HN-start\n\
+                start-kj\n\
+                dc-start\n\
+                dc-HN\n\
+                LN-dc\n\
+                HN-end\n\
+                kj-sa\n\
+                kj-HN\n\
+                kj-dc",
+        part1: Some("19"),
+        part2: Some("103"),
+    },
+    Example {
+        input: "fs-end\n\
+                he-DX\n\
+                fs-he\n\
+                start-DX\n\
+                pj-DX\n\
+                end-zg\n\
+                zg-sl\n\
+                zg-pj\n\
+                pj-he\n\
+                RW-he\n\
+                fs-DX\n\
+                pj-RW\n\
+                zg-RW\n\
+                start-pj\n\
+                he-WI\n\
+                zg-he\n\
+                pj-fs\n\
+                start-RW",
+        part1: Some("226"),
+        part2: Some("3509"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 12;
+    const TITLE: &'static str = "Passage Pathing";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Caves::new(input)?;
+        Ok(input.into())
+    }
+
+    fn part1(caves: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(&Caves::new(caves)?).into())
+    }
+
+    fn part2(caves: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(&Caves::new(caves)?).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let caves = Caves::new(
-            "start-A\n\
-             start-b\n\
-             A-c\n\
-             A-b\n\
-             b-d\n\
-             A-end\n\
-             b-end",
-        );
+        let caves = Caves::new(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&caves), 10);
 
-        let caves = Caves::new(
-            "dc-end\n\
-             HN-start\n\
-             start-kj\n\
-             dc-start\n\
-             dc-HN\n\
-             LN-dc\n\
-             HN-end\n\
-             kj-sa\n\
-             kj-HN\n\
-             kj-dc",
-        );
+        let caves = Caves::new(EXAMPLES[1].input).unwrap();
         assert_eq!(part1(&caves), 19);
 
-        let caves = Caves::new(
-            "fs-end\n\
-             he-DX\n\
-             fs-he\n\
-             start-DX\n\
-             pj-DX\n\
-             end-zg\n\
-             zg-sl\n\
-             zg-pj\n\
-             pj-he\n\
-             RW-he\n\
-             fs-DX\n\
-             pj-RW\n\
-             zg-RW\n\
-             start-pj\n\
-             he-WI\n\
-             zg-he\n\
-             pj-fs\n\
-             start-RW",
-        );
+        let caves = Caves::new(EXAMPLES[2].input).unwrap();
         assert_eq!(part1(&caves), 226);
     }
 
     #[test]
     fn test02() {
-        let caves = Caves::new(
-            "start-A\n\
-             start-b\n\
-             A-c\n\
-             A-b\n\
-             b-d\n\
-             A-end\n\
-             b-end",
-        );
+        let caves = Caves::new(EXAMPLES[0].input).unwrap();
         assert_eq!(part2(&caves), 36);
 
-        let caves = Caves::new(
-            "dc-end\n\
-             HN-start\n\
-             start-kj\n\
-             dc-start\n\
-             dc-HN\n\
-             LN-dc\n\
-             HN-end\n\
-             kj-sa\n\
-             kj-HN\n\
-             kj-dc",
-        );
+        let caves = Caves::new(EXAMPLES[1].input).unwrap();
         assert_eq!(part2(&caves), 103);
 
-        let caves = Caves::new(
-            "fs-end\n\
-             he-DX\n\
-             fs-he\n\
-             start-DX\n\
-             pj-DX\n\
-             end-zg\n\
-             zg-sl\n\
-             zg-pj\n\
-             pj-he\n\
-             RW-he\n\
-             fs-DX\n\
-             pj-RW\n\
-             zg-RW\n\
-             start-pj\n\
-             he-WI\n\
-             zg-he\n\
-             pj-fs\n\
-             start-RW",
-        );
+        let caves = Caves::new(EXAMPLES[2].input).unwrap();
         assert_eq!(part2(&caves), 3509);
     }
 }
@@ -1,6 +1,9 @@
 use std::collections::HashSet;
 use std::str::FromStr;
 
+use crate::error::{blocks, parse_lines, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone, Copy)]
 enum Axis {
     X,
@@ -27,10 +30,17 @@ impl FromStr for Fold {
     type Err = String;
 
     fn from_str(pt: &str) -> Result<Self, Self::Err> {
-        let mut pt = pt.split('=');
+        let (axis, val) = pt
+            .strip_prefix("fold along ")
+            .and_then(|fold| fold.split_once('='))
+            .ok_or("Expected a fold")?;
+        let axis = match axis.chars().collect::<Vec<_>>()[..] {
+            [c] => Axis::new(c),
+            _ => None,
+        };
         Ok(Self {
-            axis: Axis::new(pt.next().unwrap().chars().last().unwrap()).ok_or("Invalid axis")?,
-            val: pt.next().unwrap().parse().map_err(|_| "Invalid val")?,
+            axis: axis.ok_or("Invalid axis")?,
+            val: val.parse().map_err(|_| "Invalid val")?,
         })
     }
 }
@@ -45,10 +55,10 @@ impl FromStr for Point {
     type Err = String;
 
     fn from_str(pt: &str) -> Result<Self, Self::Err> {
-        let mut pt = pt.split(',');
+        let (x, y) = pt.split_once(',').ok_or("Expected a point")?;
         Ok(Self {
-            x: pt.next().unwrap().parse().map_err(|_| "Invalid x")?,
-            y: pt.next().unwrap().parse().map_err(|_| "Invalid y")?,
+            x: x.parse().map_err(|_| "Invalid x")?,
+            y: y.parse().map_err(|_| "Invalid y")?,
         })
     }
 }
@@ -102,65 +112,89 @@ fn part2(pts: &HashSet<Point>, folds: &[Fold]) -> String {
     )
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d13.txt");
-    let (pts, folds) = {
-        let mut fields = input.split("\n\n");
-        (fields.next().unwrap(), fields.next().unwrap())
-    };
-    let pts = pts
-        .lines()
-        .map(str::parse)
-        .collect::<Result<HashSet<_>, _>>()?;
-    let folds = folds
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = part1(&pts, &folds);
-    let out2 = part2(&pts, &folds);
-    Ok(format!("{}\n{}", out1, out2))
+pub const INPUT: &str = include_str!("input/d13.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "6,10\n\
+            0,14\n\
+            9,10\n\
+            0,3\n\
+            10,4\n\
+            4,11\n\
+            6,0\n\
+            6,12\n\
+            4,1\n\
+            0,13\n\
+            10,12\n\
+            3,4\n\
+            3,0\n\
+            8,4\n\
+            1,10\n\
+            2,14\n\
+            8,10\n\
+            9,0\n\
+            \n\
+            fold along y=7\n\
+            fold along x=5",
+    part1: Some("17"),
+    part2: Some(
+        "█████\n\
+         █   █\n\
+         █   █\n\
+         █   █\n\
+         █████",
+    ),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 13;
+    const TITLE: &'static str = "Transparent Origami";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (HashSet<Point>, Vec<Fold>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut blocks = blocks(input);
+        let (_, pts) = blocks.next().unwrap_or_default();
+        let pts = parse_lines(pts)?;
+        let folds = match blocks.next() {
+            Some((skip, folds)) => parse_lines(folds).map_err(|err| err.offset(skip))?,
+            None => vec![],
+        };
+        if folds.is_empty() {
+            let end = input.lines().count() + 1;
+            return Err(Error::parse(end, 1, "", "Expected folds"));
+        }
+        Ok((pts.into_iter().collect(), folds))
+    }
+
+    fn part1((pts, folds): &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(pts, folds).into())
+    }
+
+    fn part2((pts, folds): &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::image(&part2(pts, folds)))
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let pts = [
-            (6, 10),
-            (0, 14),
-            (9, 10),
-            (0, 3),
-            (10, 4),
-            (4, 11),
-            (6, 0),
-            (6, 12),
-            (4, 1),
-            (0, 13),
-            (10, 12),
-            (3, 4),
-            (3, 0),
-            (8, 4),
-            (1, 10),
-            (2, 14),
-            (8, 10),
-            (9, 0),
-        ]
-        .iter()
-        .map(|&(x, y)| Point { x, y })
-        .collect();
-        let folds = [
-            Fold {
-                axis: Axis::Y,
-                val: 7,
-            },
-            Fold {
-                axis: Axis::X,
-                val: 5,
-            },
-        ];
+        let (pts, folds) = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&pts, &folds), 17);
     }
+
+    #[test]
+    fn test02() {
+        let (pts, folds) = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(part2(&pts, &folds), "█████\n█   █\n█   █\n█   █\n█████");
+    }
 }
@@ -4,6 +4,9 @@ use std::str::FromStr;
 
 use itertools::{Itertools, MinMaxResult};
 
+use crate::error::{blocks, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 type Rules = HashMap<(char, char), char>;
 
 #[derive(Debug, Clone, Default)]
@@ -30,28 +33,30 @@ struct Polymer {
 }
 
 impl FromStr for Polymer {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(poly: &str) -> Result<Self, Self::Err> {
-        let (poly, rules) = {
-            let mut lines = poly.split("\n\n");
-            (lines.next().unwrap(), lines.next().unwrap())
-        };
+        let mut blocks = blocks(poly);
+        let (_, template) = blocks.next().unwrap_or_default();
+        let (skip, rules) = blocks.next().unwrap_or_default();
+        let rules = rules
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                let rule = line.split_once(" -> ").and_then(|(left, right)| {
+                    let left = left.chars().collect::<Vec<_>>();
+                    let right = right.chars().collect::<Vec<_>>();
+                    match (&left[..], &right[..]) {
+                        ([c1, c2], [c]) => Some(((*c1, *c2), *c)),
+                        _ => None,
+                    }
+                });
+                rule.ok_or_else(|| Error::parse(skip + idx + 1, 1, line, "Expected a rule"))
+            })
+            .collect::<Result<_, _>>()?;
         Ok(Self {
-            poly: poly.chars().collect(),
-            rules: rules
-                .lines()
-                .map(|rule| {
-                    let (left, right) = {
-                        let mut sides = rule.split(" -> ");
-                        (sides.next().unwrap(), sides.next().unwrap())
-                    };
-                    (
-                        (left.chars().next().unwrap(), left.chars().nth(1).unwrap()),
-                        right.chars().next().unwrap(),
-                    )
-                })
-                .collect(),
+            poly: template.chars().collect(),
+            rules,
         })
     }
 }
@@ -80,7 +85,7 @@ impl Polymer {
     }
 }
 
-fn run_poly(poly: &Polymer, n: u64) -> Result<usize, String> {
+fn run_poly(poly: &Polymer, n: u64) -> Result<usize, Error> {
     let mut cache = HashMap::new();
     let counts = poly
         .poly
@@ -94,86 +99,81 @@ fn run_poly(poly: &Polymer, n: u64) -> Result<usize, String> {
     if let MinMaxResult::MinMax(min, max) = counts.0.values().minmax() {
         Ok(max - min)
     } else {
-        Err("Couldn't find distinct min and max".into())
+        Err(Error::no_solution("Couldn't find distinct min and max"))
     }
 }
 
-fn part1(poly: &Polymer) -> Result<usize, String> {
+fn part1(poly: &Polymer) -> Result<usize, Error> {
     run_poly(poly, 10)
 }
 
-fn part2(poly: &Polymer) -> Result<usize, String> {
+fn part2(poly: &Polymer) -> Result<usize, Error> {
     run_poly(poly, 40)
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d14.txt");
-    let poly = input.parse::<Polymer>()?;
-    let out1 = part1(&poly)?;
-    let out2 = part2(&poly)?;
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d14.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "NNCB\n\
+            \n\
+            CH -> B\n\
+            HH -> N\n\
+            CB -> H\n\
+            NH -> C\n\
+            HB -> C\n\
+            HC -> B\n\
+            HN -> C\n\
+            NN -> C\n\
+            BH -> H\n\
+            NC -> B\n\
+            NB -> B\n\
+            BN -> B\n\
+            BB -> N\n\
+            BC -> B\n\
+            CC -> N\n\
+            CN -> C",
+    part1: Some("1588"),
+    part2: Some("2188189693529"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 14;
+    const TITLE: &'static str = "Extended Polymerization";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Polymer;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(poly: &Self::Input) -> Result<Answer, Error> {
+        part1(poly).map(Answer::from)
+    }
+
+    fn part2(poly: &Self::Input) -> Result<Answer, Error> {
+        part2(poly).map(Answer::from)
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let poly = Polymer {
-            poly: vec!['N', 'N', 'C', 'B'],
-            rules: [
-                (('C', 'H'), 'B'),
-                (('H', 'H'), 'N'),
-                (('C', 'B'), 'H'),
-                (('N', 'H'), 'C'),
-                (('H', 'B'), 'C'),
-                (('H', 'C'), 'B'),
-                (('H', 'N'), 'C'),
-                (('N', 'N'), 'C'),
-                (('B', 'H'), 'H'),
-                (('N', 'C'), 'B'),
-                (('N', 'B'), 'B'),
-                (('B', 'N'), 'B'),
-                (('B', 'B'), 'N'),
-                (('B', 'C'), 'B'),
-                (('C', 'C'), 'N'),
-                (('C', 'N'), 'C'),
-            ]
-            .iter()
-            .copied()
-            .collect(),
-        };
+        let poly = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&poly), Ok(1588));
     }
 
     #[test]
     fn test02() {
-        let poly = Polymer {
-            poly: vec!['N', 'N', 'C', 'B'],
-            rules: [
-                (('C', 'H'), 'B'),
-                (('H', 'H'), 'N'),
-                (('C', 'B'), 'H'),
-                (('N', 'H'), 'C'),
-                (('H', 'B'), 'C'),
-                (('H', 'C'), 'B'),
-                (('H', 'N'), 'C'),
-                (('N', 'N'), 'C'),
-                (('B', 'H'), 'H'),
-                (('N', 'C'), 'B'),
-                (('N', 'B'), 'B'),
-                (('B', 'N'), 'B'),
-                (('B', 'B'), 'N'),
-                (('B', 'C'), 'B'),
-                (('C', 'C'), 'N'),
-                (('C', 'N'), 'C'),
-            ]
-            .iter()
-            .copied()
-            .collect(),
-        };
+        let poly = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part2(&poly), Ok(2_188_189_693_529));
     }
 }
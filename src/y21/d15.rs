@@ -1,6 +1,9 @@
 use std::cmp;
 use std::collections::{BinaryHeap, HashMap, HashSet};
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct PointDist((usize, usize), u64);
 
@@ -86,61 +89,74 @@ fn part2(mut risks: Vec<Vec<u64>>) -> u64 {
     find_path(&risks)
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d15.txt");
-    let risks = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| {
-                    c.to_digit(10)
-                        .map(u64::from)
-                        .ok_or_else(|| format!("Invalid digit: {}", c))
-                })
-                .collect::<Result<Vec<_>, _>>()
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = part1(&risks);
-    let out2 = part2(risks);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d15.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "1163751742\n\
+            1381373672\n\
+            2136511328\n\
+            3694931569\n\
+            7463417111\n\
+            1319128137\n\
+            1359912421\n\
+            3125421639\n\
+            1293138521\n\
+            2311944581",
+    part1: Some("40"),
+    part2: Some("315"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 15;
+    const TITLE: &'static str = "Chiton";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| {
+                        c.to_digit(10).map(u64::from).ok_or_else(|| {
+                            Error::parse(row + 1, col + 1, line, format!("Invalid digit: {}", c))
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(risks: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(risks).into())
+    }
+
+    fn part2(risks: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(risks.clone()).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let risks = [
-            vec![1, 1, 6, 3, 7, 5, 1, 7, 4, 2],
-            vec![1, 3, 8, 1, 3, 7, 3, 6, 7, 2],
-            vec![2, 1, 3, 6, 5, 1, 1, 3, 2, 8],
-            vec![3, 6, 9, 4, 9, 3, 1, 5, 6, 9],
-            vec![7, 4, 6, 3, 4, 1, 7, 1, 1, 1],
-            vec![1, 3, 1, 9, 1, 2, 8, 1, 3, 7],
-            vec![1, 3, 5, 9, 9, 1, 2, 4, 2, 1],
-            vec![3, 1, 2, 5, 4, 2, 1, 6, 3, 9],
-            vec![1, 2, 9, 3, 1, 3, 8, 5, 2, 1],
-            vec![2, 3, 1, 1, 9, 4, 4, 5, 8, 1],
-        ];
+        let risks = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(&risks), 40);
     }
 
     #[test]
     fn test02() {
-        let risks = vec![
-            vec![1, 1, 6, 3, 7, 5, 1, 7, 4, 2],
-            vec![1, 3, 8, 1, 3, 7, 3, 6, 7, 2],
-            vec![2, 1, 3, 6, 5, 1, 1, 3, 2, 8],
-            vec![3, 6, 9, 4, 9, 3, 1, 5, 6, 9],
-            vec![7, 4, 6, 3, 4, 1, 7, 1, 1, 1],
-            vec![1, 3, 1, 9, 1, 2, 8, 1, 3, 7],
-            vec![1, 3, 5, 9, 9, 1, 2, 4, 2, 1],
-            vec![3, 1, 2, 5, 4, 2, 1, 6, 3, 9],
-            vec![1, 2, 9, 3, 1, 3, 8, 5, 2, 1],
-            vec![2, 3, 1, 1, 9, 4, 4, 5, 8, 1],
-        ];
+        let risks = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part2(risks), 315);
     }
 }
@@ -1,6 +1,5 @@
 use std::convert::{TryFrom, TryInto};
 
-use itertools::Itertools;
 use nom::{
     bits::complete::take,
     combinator::{map, map_res},
@@ -9,6 +8,9 @@ use nom::{
     IResult,
 };
 
+use crate::error::Error;
+use crate::solution::{Answer, Example, Run, Solution};
+
 type Bits<'a> = (&'a [u8], usize);
 
 fn take_bool(bits: Bits<'_>) -> IResult<Bits<'_>, bool> {
@@ -166,20 +168,41 @@ struct Packet {
     payload: Payload,
 }
 
-fn hex_to_bits(hex: &str) -> Vec<u8> {
-    hex.chars()
+fn hex_to_bits(hex: &str) -> Result<Vec<u8>, Error> {
+    let digits = hex
+        .chars()
+        .enumerate()
+        .map(|(col, c)| {
+            #[allow(clippy::cast_possible_truncation)]
+            c.to_digit(16)
+                .map(|d| d as u8)
+                .ok_or_else(|| Error::parse(1, col + 1, hex, "Invalid hex digit"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(digits
         .chunks(2)
-        .into_iter()
-        .map(|hex| u8::from_str_radix(&hex.collect::<String>(), 16).unwrap())
-        .collect()
+        .map(|pair| pair[0] << 4 | pair.get(1).copied().unwrap_or(0))
+        .collect())
 }
 
 impl Packet {
-    fn parse(hex: &str) -> Result<Self, String> {
-        let bits = hex_to_bits(hex);
+    fn parse(hex: &str) -> Result<Self, Error> {
+        let bits = hex_to_bits(hex)?;
         Self::parse_bits((&bits, 0))
             .map(|(_, packet)| packet)
-            .map_err(|e| format!("{:?}", e))
+            .map_err(|err| {
+                let (rest, off) = match err {
+                    nom::Err::Error(err) | nom::Err::Failure(err) => err.input,
+                    nom::Err::Incomplete(_) => (&[][..], 0),
+                };
+                let bit = (bits.len() - rest.len()) * 8 + off;
+                Error::parse(
+                    1,
+                    bit / 4 + 1,
+                    hex,
+                    format!("Invalid packet at bit {}", bit),
+                )
+            })
     }
 
     fn parse_bits(bits: Bits<'_>) -> IResult<Bits<'_>, Self> {
@@ -206,23 +229,106 @@ impl Packet {
     }
 }
 
-fn part1(hex: &str) -> Result<u64, String> {
+fn part1(hex: &str) -> Result<u64, Error> {
     Packet::parse(hex).map(|pkt| pkt.version_sum())
 }
 
-fn part2(hex: &str) -> Result<u64, String> {
+fn part2(hex: &str) -> Result<u64, Error> {
     Packet::parse(hex).map(|pkt| pkt.eval())
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d16.txt");
-    let hex = input.trim();
-    let out1 = part1(hex)?;
-    let out2 = part2(hex)?;
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d16.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "8A004A801A8002F478",
+        part1: Some("16"),
+        part2: None,
+    },
+    Example {
+        input: "620080001611562C8802118E34",
+        part1: Some("12"),
+        part2: None,
+    },
+    Example {
+        input: "C0015000016115A2E0802F182340",
+        part1: Some("23"),
+        part2: None,
+    },
+    Example {
+        input: "A0016C880162017C3686B18A3D4780",
+        part1: Some("31"),
+        part2: None,
+    },
+    Example {
+        input: "C200B40A82",
+        part1: None,
+        part2: Some("3"),
+    },
+    Example {
+        input: "04005AC33890",
+        part1: None,
+        part2: Some("54"),
+    },
+    Example {
+        input: "880086C3E88112",
+        part1: None,
+        part2: Some("7"),
+    },
+    Example {
+        input: "CE00C43D881120",
+        part1: None,
+        part2: Some("9"),
+    },
+    Example {
+        input: "D8005AC2A8F0",
+        part1: None,
+        part2: Some("1"),
+    },
+    Example {
+        input: "F600BC2D8F",
+        part1: None,
+        part2: Some("0"),
+    },
+    Example {
+        input: "9C005AC2F8F0",
+        part1: None,
+        part2: Some("0"),
+    },
+    Example {
+        input: "9C0141080250320F1802104A08",
+        part1: None,
+        part2: Some("1"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 16;
+    const TITLE: &'static str = "Packet Decoder";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let hex = input.trim();
+        Packet::parse(hex)?;
+        Ok(hex.into())
+    }
+
+    fn part1(hex: &Self::Input) -> Result<Answer, Error> {
+        part1(hex).map(Answer::from)
+    }
+
+    fn part2(hex: &Self::Input) -> Result<Answer, Error> {
+        part2(hex).map(Answer::from)
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -255,11 +361,15 @@ mod tests {
 
     #[test]
     fn text_hex_to_bits() {
-        assert_eq!(hex_to_bits("00"), vec![0b0000_0000]);
-        assert_eq!(hex_to_bits("FE"), vec![0b1111_1110]);
+        assert_eq!(hex_to_bits("00"), Ok(vec![0b0000_0000]));
+        assert_eq!(hex_to_bits("FE"), Ok(vec![0b1111_1110]));
         assert_eq!(
             hex_to_bits("D2FE28"),
-            vec![0b1101_0010, 0b1111_1110, 0b0010_1000]
+            Ok(vec![0b1101_0010, 0b1111_1110, 0b0010_1000])
+        );
+        assert_eq!(
+            hex_to_bits("D2G"),
+            Err(Error::parse(1, 3, "D2G", "Invalid hex digit"))
         );
     }
 
@@ -326,21 +436,21 @@ mod tests {
 
     #[test]
     fn test01() {
-        assert_eq!(part1("8A004A801A8002F478"), Ok(16));
-        assert_eq!(part1("620080001611562C8802118E34"), Ok(12));
-        assert_eq!(part1("C0015000016115A2E0802F182340"), Ok(23));
-        assert_eq!(part1("A0016C880162017C3686B18A3D4780"), Ok(31));
+        assert_eq!(part1(EXAMPLES[0].input), Ok(16));
+        assert_eq!(part1(EXAMPLES[1].input), Ok(12));
+        assert_eq!(part1(EXAMPLES[2].input), Ok(23));
+        assert_eq!(part1(EXAMPLES[3].input), Ok(31));
     }
 
     #[test]
     fn test02() {
-        assert_eq!(part2("C200B40A82"), Ok(3));
-        assert_eq!(part2("04005AC33890"), Ok(54));
-        assert_eq!(part2("880086C3E88112"), Ok(7));
-        assert_eq!(part2("CE00C43D881120"), Ok(9));
-        assert_eq!(part2("D8005AC2A8F0"), Ok(1));
-        assert_eq!(part2("F600BC2D8F"), Ok(0));
-        assert_eq!(part2("9C005AC2F8F0"), Ok(0));
-        assert_eq!(part2("9C0141080250320F1802104A08"), Ok(1));
+        assert_eq!(part2(EXAMPLES[4].input), Ok(3));
+        assert_eq!(part2(EXAMPLES[5].input), Ok(54));
+        assert_eq!(part2(EXAMPLES[6].input), Ok(7));
+        assert_eq!(part2(EXAMPLES[7].input), Ok(9));
+        assert_eq!(part2(EXAMPLES[8].input), Ok(1));
+        assert_eq!(part2(EXAMPLES[9].input), Ok(0));
+        assert_eq!(part2(EXAMPLES[10].input), Ok(0));
+        assert_eq!(part2(EXAMPLES[11].input), Ok(1));
     }
 }
@@ -1,3 +1,6 @@
+use crate::error::{column, parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 // TODO: Should be able to compute this from the x velocity and the target
 // area.
 const MAX_Y: i64 = 999;
@@ -63,32 +66,52 @@ fn part2(tgt: Target) -> usize {
         .count()
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d17.txt");
-    let tgt = {
-        let mut fields = input["target area: ".len()..].trim().split(", ");
-        let (minx, maxx) = {
-            let mut xs = fields.next().unwrap()[2..]
-                .split("..")
-                .map(str::parse)
-                .map(Result::unwrap);
-            (xs.next().unwrap(), xs.next().unwrap())
-        };
-        let (miny, maxy) = {
-            let mut ys = fields.next().unwrap()[2..]
-                .split("..")
-                .map(str::parse)
-                .map(Result::unwrap);
-            (ys.next().unwrap(), ys.next().unwrap())
-        };
-        (minx, maxx, miny, maxy)
-    };
-    let out1 = part1(tgt);
-    let out2 = part2(tgt);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d17.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "target area: x=20..30, y=-10..-5",
+    part1: Some("45"),
+    part2: Some("112"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 17;
+    const TITLE: &'static str = "Trick Shot";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Target;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let line = input.trim();
+        let (xs, ys) = line
+            .strip_prefix("target area: x=")
+            .and_then(|target| target.split_once(", y="))
+            .ok_or_else(|| Error::parse(1, 1, line, "Expected a target area"))?;
+        let range = |range: &str| Error::parse(1, column(line, range), line, "Expected a range");
+        let (minx, maxx) = xs.split_once("..").ok_or_else(|| range(xs))?;
+        let (miny, maxy) = ys.split_once("..").ok_or_else(|| range(ys))?;
+        Ok((
+            parse_field(1, line, minx)?,
+            parse_field(1, line, maxx)?,
+            parse_field(1, line, miny)?,
+            parse_field(1, line, maxy)?,
+        ))
+    }
+
+    fn part1(tgt: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(*tgt).into())
+    }
+
+    fn part2(tgt: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(*tgt).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,11 +127,13 @@ mod tests {
 
     #[test]
     fn test01() {
-        assert_eq!(part1((20, 30, -10, -5)), 45);
+        let tgt = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(part1(tgt), 45);
     }
 
     #[test]
     fn test02() {
-        assert_eq!(part2((20, 30, -10, -5)), 112);
+        let tgt = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(part2(tgt), 112);
     }
 }
@@ -11,6 +11,9 @@ use nom::{
     IResult,
 };
 
+use crate::error::{column, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone, Copy)]
 enum LeftRight<A> {
     Left(A),
@@ -42,10 +45,16 @@ impl Add for Num {
 }
 
 impl Num {
-    fn parse(num: &str) -> Result<Self, String> {
-        Self::parse_pair(num)
-            .map(|(_, num)| num)
-            .map_err(|e| format!("{:?}", e))
+    // Parse a number on its own line, reporting errors as if on line 1.
+    fn parse(num: &str) -> Result<Self, Error> {
+        let rest = match Self::parse_pair(num) {
+            Ok(("", num)) => return Ok(num),
+            Ok((rest, _)) => rest,
+            Err(nom::Err::Error(err) | nom::Err::Failure(err)) => err.input,
+            Err(nom::Err::Incomplete(_)) => &num[num.len()..],
+        };
+        let col = column(num, rest);
+        Err(Error::parse(1, col, num, "Invalid snailfish number"))
     }
 
     fn parse_pair(num: &str) -> IResult<&str, Self> {
@@ -198,18 +207,68 @@ fn part2(nums: Vec<Num>) -> u64 {
         .unwrap()
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d18.txt");
-    let nums = input
-        .lines()
-        .map(Num::parse)
-        .collect::<Result<Vec<_>, _>>()?;
-    let out1 = part1(nums.clone());
-    let out2 = part2(nums);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d18.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]\n\
+                [7,[[[3,7],[4,3]],[[6,3],[8,8]]]]\n\
+                [[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]\n\
+                [[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]\n\
+                [7,[5,[[3,8],[1,4]]]]\n\
+                [[2,[2,2]],[8,[8,1]]]\n\
+                [2,9]\n\
+                [1,[[[9,3],9],[[9,0],[0,7]]]]\n\
+                [[[5,[7,4]],7],1]\n\
+                [[[[4,2],2],6],[8,7]]",
+        part1: Some("3488"),
+        part2: None,
+    },
+    Example {
+        input: "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]\n\
+                [[[5,[2,8]],4],[5,[[9,9],0]]]\n\
+                [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]\n\
+                [[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]\n\
+                [[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]\n\
+                [[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]\n\
+                [[[[5,4],[7,7]],8],[[8,3],8]]\n\
+                [[9,3],[[9,9],[6,[4,9]]]]\n\
+                [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]\n\
+                [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+        part1: Some("4140"),
+        part2: Some("3993"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 18;
+    const TITLE: &'static str = "Snailfish";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Num>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| Num::parse(line).map_err(|err| err.offset(row)))
+            .collect()
+    }
+
+    fn part1(nums: &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(nums.clone()).into())
+    }
+
+    fn part2(nums: &Self::Input) -> Result<Answer, Error> {
+        Ok(part2(nums.clone()).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -281,18 +340,7 @@ mod tests {
             "[[[[5,0],[7,4]],[5,5]],[6,6]]",
         );
         test(
-            &[
-                "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
-                "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
-                "[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]",
-                "[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]",
-                "[7,[5,[[3,8],[1,4]]]]",
-                "[[2,[2,2]],[8,[8,1]]]",
-                "[2,9]",
-                "[1,[[[9,3],9],[[9,0],[0,7]]]]",
-                "[[[5,[7,4]],7],1]",
-                "[[[[4,2],2],6],[8,7]]",
-            ],
+            &EXAMPLES[0].input.lines().collect::<Vec<_>>(),
             "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
         );
     }
@@ -316,45 +364,15 @@ mod tests {
 
     #[test]
     fn test01() {
-        let xs = [
-            "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
-            "[[[5,[2,8]],4],[5,[[9,9],0]]]",
-            "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
-            "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
-            "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
-            "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
-            "[[[[5,4],[7,7]],8],[[8,3],8]]",
-            "[[9,3],[[9,9],[6,[4,9]]]]",
-            "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
-            "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
-        ]
-        .iter()
-        .copied()
-        .map(Num::parse)
-        .map(Result::unwrap)
-        .collect::<Vec<_>>();
+        let xs = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(part1(xs), 3488);
+        let xs = Solver::parse(EXAMPLES[1].input).unwrap();
         assert_eq!(part1(xs), 4140);
     }
 
     #[test]
     fn test02() {
-        let xs = [
-            "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
-            "[[[5,[2,8]],4],[5,[[9,9],0]]]",
-            "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
-            "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
-            "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
-            "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
-            "[[[[5,4],[7,7]],8],[[8,3],8]]",
-            "[[9,3],[[9,9],[6,[4,9]]]]",
-            "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
-            "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
-        ]
-        .iter()
-        .copied()
-        .map(Num::parse)
-        .map(Result::unwrap)
-        .collect::<Vec<_>>();
+        let xs = Solver::parse(EXAMPLES[1].input).unwrap();
         assert_eq!(part2(xs), 3993);
     }
 }
@@ -2,6 +2,9 @@ use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 use std::str::FromStr;
 
+use crate::error::{blocks, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Pixel {
     Light,
@@ -44,17 +47,21 @@ struct Algorithm {
 }
 
 impl FromStr for Algorithm {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(alg: &str) -> Result<Self, Self::Err> {
+        let pixels = alg
+            .chars()
+            .enumerate()
+            .map(|(col, pix)| {
+                Pixel::try_from(pix).map_err(|_| Error::parse(1, col + 1, alg, "Invalid pixel"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let len = pixels.len();
         Ok(Self {
-            pixels: alg
-                .chars()
-                .map(Pixel::try_from)
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|c| format!("Invalid pixel: {}", c))?
-                .try_into()
-                .map_err(|_| "Invalid algorithm length")?,
+            pixels: pixels.try_into().map_err(|_| {
+                Error::parse(1, 1, alg, format!("Expected 512 pixels, got {}", len))
+            })?,
         })
     }
 }
@@ -79,7 +86,7 @@ struct Image {
 }
 
 impl FromStr for Image {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(img: &str) -> Result<Self, Self::Err> {
         Ok(Self {
@@ -88,12 +95,13 @@ impl FromStr for Image {
                 .enumerate()
                 .flat_map(|(r, line)| {
                     line.chars().enumerate().map(move |(c, pix)| {
+                        let pix = Pixel::try_from(pix)
+                            .map_err(|_| Error::parse(r + 1, c + 1, line, "Invalid pixel"))?;
                         #[allow(clippy::cast_possible_wrap)]
-                        Pixel::try_from(pix).map(|pix| ((r as isize, c as isize), pix))
+                        Ok(((r as isize, c as isize), pix))
                     })
                 })
-                .collect::<Result<HashMap<_, _>, _>>()
-                .map_err(|c| format!("Invalid pixel: {}", c))?,
+                .collect::<Result<HashMap<_, _>, _>>()?,
             bg: Pixel::Dark,
         })
     }
@@ -152,35 +160,60 @@ fn part1(mut img: Image, alg: &Algorithm, n: u64) -> usize {
     img.count()
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d20.txt");
-    let (alg, img) = {
-        let mut blocks = input.split("\n\n");
-        (
-            blocks.next().unwrap().parse::<Algorithm>()?,
-            blocks.next().unwrap().parse::<Image>()?,
-        )
-    };
-    let out1 = part1(img.clone(), &alg, 2);
-    let out2 = part1(img, &alg, 50);
-    Ok(format!("{} {}", out1, out2))
+pub const INPUT: &str = include_str!("input/d20.txt");
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#\n\
+                \n\
+                #..#.\n\
+                #....\n\
+                ##..#\n\
+                ..#..\n\
+                ..###",
+        part1: Some("35"),
+        part2: Some("3351"),
+    },
+];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 20;
+    const TITLE: &'static str = "Trench Map";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (Algorithm, Image);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut blocks = blocks(input);
+        let (_, alg) = blocks.next().unwrap_or_default();
+        let alg = alg.parse::<Algorithm>()?;
+        let (skip, img) = blocks
+            .next()
+            .ok_or_else(|| Error::parse(input.lines().count() + 1, 1, "", "Expected an image"))?;
+        Ok((alg, img.parse().map_err(|err: Error| err.offset(skip))?))
+    }
+
+    fn part1((alg, img): &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(img.clone(), alg, 2).into())
+    }
+
+    fn part2((alg, img): &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(img.clone(), alg, 50).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        let alg = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#".parse().unwrap();
-        let img = "#..#.\n\
-                   #....\n\
-                   ##..#\n\
-                   ..#..\n\
-                   ..###"
-            .parse::<Image>()
-            .unwrap();
+        let (alg, img) = Solver::parse(EXAMPLES[0].input).unwrap();
         assert_eq!(part1(img.clone(), &alg, 2), 35);
         assert_eq!(part1(img, &alg, 50), 3351);
     }
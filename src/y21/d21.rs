@@ -3,6 +3,9 @@ use std::iter;
 
 use itertools::Itertools;
 
+use crate::error::{parse_field, Error};
+use crate::solution::{Answer, Example, Run, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Game {
     positions: [u64; 2],
@@ -101,30 +104,72 @@ fn part2(p1: u64, p2: u64) -> [u64; 2] {
     [games.wins(0), games.wins(1)]
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn run() -> Result<String, String> {
-    let input = include_str!("input/d21.txt");
-    let (p1, p2) = {
-        let parse = |line: &str| u64::from(line.chars().last().unwrap().to_digit(10).unwrap());
-        let mut lines = input.lines();
-        (parse(lines.next().unwrap()), parse(lines.next().unwrap()))
-    };
-    let out1 = part1(p1, p2);
-    let out2 = part2(p1, p2);
-    Ok(format!("{} {:?}", out1, out2))
+pub const INPUT: &str = include_str!("input/d21.txt");
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "Player 1 starting position: 4\n\
+            Player 2 starting position: 8",
+    part1: Some("739785"),
+    part2: Some("444356092776315"),
+}];
+
+struct Solver;
+
+impl Solution for Solver {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 21;
+    const TITLE: &'static str = "Dirac Dice";
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (u64, u64);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let players = input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                let (_, pos) = line.split_once(" starting position: ").ok_or_else(|| {
+                    Error::parse(row + 1, 1, line, "Expected a starting position")
+                })?;
+                parse_field(row + 1, line, pos)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        match players[..] {
+            [p1, p2] => Ok((p1, p2)),
+            _ => Err(Error::parse(
+                players.len().min(2) + 1,
+                1,
+                input.lines().nth(2).unwrap_or_default(),
+                "Expected two players",
+            )),
+        }
+    }
+
+    fn part1((p1, p2): &Self::Input) -> Result<Answer, Error> {
+        Ok(part1(*p1, *p2).into())
+    }
+
+    fn part2((p1, p2): &Self::Input) -> Result<Answer, Error> {
+        let [wins1, wins2] = part2(*p1, *p2);
+        Ok(wins1.max(wins2).into())
+    }
 }
 
+pub const SOLVER: &dyn Run = &Solver;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test01() {
-        assert_eq!(part1(4, 8), 739_785);
+        let (p1, p2) = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(part1(p1, p2), 739_785);
     }
 
     #[test]
     fn test02() {
-        assert_eq!(part2(4, 8), [444_356_092_776_315, 341_960_390_180_808]);
+        let (p1, p2) = Solver::parse(EXAMPLES[0].input).unwrap();
+        assert_eq!(part2(p1, p2), [444_356_092_776_315, 341_960_390_180_808]);
     }
 }
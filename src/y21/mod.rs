@@ -1,37 +1,36 @@
-use crate::Run;
-use lazy_static::lazy_static;
+use crate::Day;
 
-mod d01;
-mod d02;
-mod d03;
-mod d04;
-mod d05;
-mod d06;
-mod d07;
-mod d08;
-mod d09;
-mod d10;
-mod d11;
-mod d12;
-mod d13;
-mod d14;
-mod d15;
-mod d16;
-mod d17;
-mod d18;
-mod d19;
-mod d20;
-mod d21;
-mod d22;
-mod d23;
-mod d24;
-mod d25;
+pub mod d01;
+pub mod d02;
+pub mod d03;
+pub mod d04;
+pub mod d05;
+pub mod d06;
+pub mod d07;
+pub mod d08;
+pub mod d09;
+pub mod d10;
+pub mod d11;
+pub mod d12;
+pub mod d13;
+pub mod d14;
+pub mod d15;
+pub mod d16;
+pub mod d17;
+pub mod d18;
+pub mod d19;
+pub mod d20;
+pub mod d21;
+pub mod d22;
+pub mod d23;
+pub mod d24;
+pub mod d25;
 
 pub const YEAR: usize = 21;
 
-lazy_static! {
-    pub static ref DAYS: [Box<Run>; 25] = days!(
-        d01, d02, d03, d04, d05, d06, d07, d08, d09, d10, d11, d12, d13, d14, d15, d16, d17, d18,
-        d19, d20, d21, d22, d23, d24, d25
-    );
-}
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+pub static DAYS: &[Day] = &days!(
+    d01, d02, d03, d04, d05, d06, d07, d08, d09, d10, d11, d12, d13, d14, d15, d16, d17, d18, d19,
+    d20, d21, d22, d23, d24, d25
+);
@@ -0,0 +1,7 @@
+; Count down from the input to 1, printing each value.
+        in [n]
+loop:   out [n]
+        add [n], #-1, [n]
+        jnz [n], #loop
+        hlt
+n:      .data 0
@@ -0,0 +1,49 @@
+use aoc::verify::{verify_day, Verdict};
+
+fn verify(year: usize, day: usize) {
+    if let Verdict::Wrong(errs) = verify_day(year, day).unwrap() {
+        panic!("{}", errs.join("\n"));
+    }
+}
+
+macro_rules! verify {
+    ($year:literal: $($(#[$attr:meta])* $name:ident $day:literal),* $(,)?) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                verify($year, $day);
+            }
+        )*
+    };
+}
+
+mod y19 {
+    use super::verify;
+
+    verify!(19:
+        d01 1, d02 2, d03 3, d04 4, d05 5, d06 6, d07 7, d08 8, d09 9, d10 10, d11 11,
+        #[ignore] d12 12, d13 13, d14 14, d15 15, d16 16, d17 17, d18 18, d19 19, d20 20, d21 21,
+        d22 22, d23 23, d24 24, d25 25,
+    );
+}
+
+mod y20 {
+    use super::verify;
+
+    verify!(20:
+        d01 1, d02 2, d03 3, d04 4, d05 5, d06 6, d07 7, d08 8, d09 9, d10 10, d11 11, d12 12,
+        d13 13, d14 14, #[ignore] d15 15, d16 16, #[ignore] d17 17, d18 18, d19 19,
+        #[ignore] d20 20, d21 21, #[ignore] d22 22, #[ignore] d23 23, #[ignore] d24 24, d25 25,
+    );
+}
+
+mod y21 {
+    use super::verify;
+
+    verify!(21:
+        d01 1, d02 2, d03 3, d04 4, d05 5, d06 6, d07 7, #[ignore] d08 8, d09 9, d10 10, d11 11,
+        d12 12, d13 13, d14 14, #[ignore] d15 15, d16 16, d17 17, d18 18, #[ignore] d19 19,
+        #[ignore] d20 20, d21 21, d22 22, d23 23, d24 24, d25 25,
+    );
+}